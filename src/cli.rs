@@ -1,12 +1,85 @@
-use clap::Parser;
+use clap::{Parser, Subcommand};
 use std::path::PathBuf;
 
+use crate::options::GeneratorOptions;
+
 #[derive(Parser)]
-#[command(author, version, about, long_about = None)]
+#[command(
+    author,
+    version,
+    about,
+    long_about = None,
+    args_conflicts_with_subcommands = true,
+    subcommand_negates_reqs = true
+)]
 pub struct Args {
-    #[arg(short, long, help = "Path to the OpenAPI JSON file")]
-    pub file: PathBuf,
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
+    #[command(flatten)]
+    pub generate: GenerateArgs,
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Generate MCP server code from an OpenAPI file or a workspace config.
+    Generate(GenerateArgs),
+}
+
+#[derive(clap::Args)]
+pub struct GenerateArgs {
+    #[arg(
+        short,
+        long,
+        help = "Path to the OpenAPI JSON file",
+        required_unless_present = "all",
+        conflicts_with = "all"
+    )]
+    pub file: Option<PathBuf>,
+
+    #[arg(
+        short,
+        long,
+        help = "Path to write the output directory",
+        required_unless_present = "all",
+        conflicts_with = "all"
+    )]
+    pub output: Option<PathBuf>,
+
+    // Workspaces take their generator options from the config, so option flags are rejected.
+    #[arg(
+        long,
+        help = "Generate every server listed in the workspace config, using its [options] table",
+        conflicts_with = "GeneratorOptions"
+    )]
+    pub all: bool,
+
+    #[arg(
+        long,
+        help = "Path to the workspace config",
+        default_value = "mcpify.toml",
+        requires = "all"
+    )]
+    pub config: PathBuf,
+
+    #[arg(
+        long,
+        help = "Overwrite generated files in existing output directories"
+    )]
+    pub force: bool,
+
+    #[command(flatten)]
+    pub options: GeneratorOptions,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    #[arg(short, long, help = "Path to write the output directory")]
-    pub output: PathBuf,
+    #[test]
+    fn test_all_rejects_generator_options() {
+        assert!(Args::try_parse_from(["mcpify", "generate", "--all"]).is_ok());
+        assert!(Args::try_parse_from(["mcpify", "generate", "--all", "--debug-curl"]).is_err());
+        assert!(Args::try_parse_from(["mcpify", "--all", "--max-tools", "1"]).is_err());
+    }
 }
@@ -0,0 +1,505 @@
+use std::path::{Path, PathBuf};
+
+use crate::options::{GeneratorOptions, Runtime};
+
+/// Port the containerized server listens on.
+const CONTAINER_PORT: u16 = 8080;
+
+/// Fallback name for servers whose output directory yields no usable name.
+const DEFAULT_APP_NAME: &str = "mcp-server";
+
+/// A file to write into the output directory, relative to it.
+pub struct DeploymentFile {
+    /// Path of the file, relative to the output directory.
+    pub path: PathBuf,
+
+    /// Contents of the file.
+    pub contents: String,
+}
+
+/// Returns the deployment files to emit alongside the generated server.
+///
+/// # Arguments
+///
+/// * `output_dir` - Directory where the generated code is written, used to name the deployment.
+/// * `options` - Options controlling which deployment files are emitted.
+///
+/// # Returns
+///
+/// The deployment files, empty if no deployment target is requested.
+pub fn deployment_files(output_dir: &Path, options: &GeneratorOptions) -> Vec<DeploymentFile> {
+    let mut files = Vec::new();
+    let name = app_name(output_dir);
+
+    match options.runtime {
+        Runtime::Node => {}
+        Runtime::CloudflareWorkers => files.extend(cloudflare_workers_files(&name)),
+        Runtime::AwsLambda => files.extend(aws_lambda_files()),
+    }
+
+    if options.k8s {
+        files.extend(container_files());
+        files.extend(kubernetes_manifests(
+            &name,
+            options.k8s_ingress_host.as_deref(),
+        ));
+    }
+
+    files
+}
+
+/// Derives a Kubernetes resource name (RFC 1123 label) from the output directory name.
+///
+/// # Arguments
+///
+/// * `output_dir` - Directory where the generated code is written.
+///
+/// # Returns
+///
+/// A lowercase name of at most 63 alphanumeric characters or dashes.
+fn app_name(output_dir: &Path) -> String {
+    let raw = output_dir
+        .file_name()
+        .map(|name| name.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+    let sanitized = raw
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+        .collect::<String>();
+    let name = sanitized
+        .split('-')
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("-");
+    let name = name.chars().take(63).collect::<String>();
+    let name = name.trim_end_matches('-');
+
+    if name.is_empty() {
+        DEFAULT_APP_NAME.to_string()
+    } else {
+        name.to_string()
+    }
+}
+
+/// Returns the project files of a Cloudflare Worker, replacing the Node.js package and TypeScript
+/// configuration from the templates.
+///
+/// # Arguments
+///
+/// * `name` - Name of the Worker.
+fn cloudflare_workers_files(name: &str) -> Vec<DeploymentFile> {
+    vec![
+        DeploymentFile {
+            path: PathBuf::from("wrangler.toml"),
+            contents: format!(
+                r#"# For local development, copy .env.example to .dev.vars.
+# In production, set each variable with `npx wrangler secret put <NAME>`.
+name = "{}"
+main = "src/index.ts"
+compatibility_date = "2025-06-01"
+"#,
+                name
+            ),
+        },
+        DeploymentFile {
+            path: PathBuf::from("package.json"),
+            contents: package_json(
+                &[
+                    ("build", "tsc --noEmit"),
+                    ("dev", "wrangler dev"),
+                    ("deploy", "wrangler deploy"),
+                    ("start", "pnpm run dev"),
+                ],
+                &[
+                    ("@cloudflare/workers-types", "^4.20250601.0"),
+                    ("typescript", "^5.8.3"),
+                    ("wrangler", "^4.19.1"),
+                ],
+            ),
+        },
+        DeploymentFile {
+            path: PathBuf::from("tsconfig.json"),
+            contents: r#"{
+  "compilerOptions": {
+    "module": "ES2022",
+    "moduleResolution": "Bundler",
+    "target": "ES2022",
+    "lib": ["ES2022"],
+    "types": ["@cloudflare/workers-types"],
+    "strict": false,
+    "noEmit": true
+  }
+}
+"#
+            .to_string(),
+        },
+        DeploymentFile {
+            path: PathBuf::from(".gitignore"),
+            contents: ".dev.vars\n.env\n.wrangler\nnode_modules\n".to_string(),
+        },
+    ]
+}
+
+/// Returns the project files of an AWS Lambda function: a SAM template and a package without
+/// the Node.js process dependencies from the templates.
+fn aws_lambda_files() -> Vec<DeploymentFile> {
+    vec![
+        DeploymentFile {
+            path: PathBuf::from("template.yaml"),
+            contents: r#"# Deploy with `sam build && sam deploy --guided`. The handler also accepts Lambda Function
+# URL events, so `FunctionUrlConfig` can replace the HTTP API event.
+AWSTemplateFormatVersion: "2010-09-09"
+Transform: AWS::Serverless-2016-10-31
+Description: Generated MCP server.
+
+Parameters:
+  BackendUrl:
+    Type: String
+    Description: Base URL of the backend REST API.
+  BackendAcceptLanguage:
+    Type: String
+    Default: ""
+    Description: Preferred backend response language (e.g., fr-BE), sent as Accept-Language.
+  EnablePrivilegedTools:
+    Type: String
+    Default: "0"
+    AllowedValues: ["0", "1"]
+    Description: Set to 1 to register tools for operations marked x-mcp-privileged.
+
+Resources:
+  McpFunction:
+    Type: AWS::Serverless::Function
+    Properties:
+      Runtime: nodejs22.x
+      Handler: index.handler
+      CodeUri: .
+      MemorySize: 512
+      Timeout: 30
+      Environment:
+        Variables:
+          BACKEND_URL: !Ref BackendUrl
+          BACKEND_ACCEPT_LANGUAGE: !Ref BackendAcceptLanguage
+          ENABLE_PRIVILEGED_TOOLS: !Ref EnablePrivilegedTools
+      Events:
+        Mcp:
+          Type: HttpApi
+          Properties:
+            Path: /mcp
+            Method: POST
+        Health:
+          Type: HttpApi
+          Properties:
+            Path: /healthz
+            Method: GET
+    Metadata:
+      BuildMethod: esbuild
+      BuildProperties:
+        Format: esm
+        Target: es2022
+        OutExtension:
+          - .js=.mjs
+        EntryPoints:
+          - src/index.ts
+
+Outputs:
+  McpEndpoint:
+    Description: Streamable HTTP endpoint of the MCP server.
+    Value: !Sub "https://${ServerlessHttpApi}.execute-api.${AWS::Region}.amazonaws.com/mcp"
+"#
+            .to_string(),
+        },
+        DeploymentFile {
+            path: PathBuf::from("package.json"),
+            contents: package_json(
+                &[
+                    ("build", "tsc --noEmit"),
+                    ("deploy", "sam build && sam deploy --guided"),
+                ],
+                &[
+                    ("@types/aws-lambda", "^8.10.149"),
+                    ("@types/node", "^22.15.17"),
+                    ("esbuild", "^0.25.4"),
+                    ("typescript", "^5.8.3"),
+                ],
+            ),
+        },
+    ]
+}
+
+/// Builds the package.json of a serverless server, which only depends on the MCP SDK and Zod.
+///
+/// # Arguments
+///
+/// * `scripts` - The npm scripts, as `(name, command)` pairs.
+/// * `dev_dependencies` - The development dependencies, as `(name, version)` pairs.
+fn package_json(scripts: &[(&str, &str)], dev_dependencies: &[(&str, &str)]) -> String {
+    let entries = |pairs: &[(&str, &str)]| {
+        pairs
+            .iter()
+            .map(|(key, value)| format!("    \"{}\": \"{}\"", key, value))
+            .collect::<Vec<_>>()
+            .join(",\n")
+    };
+
+    format!(
+        r#"{{
+  "name": "mcp",
+  "version": "1.0.0",
+  "description": "",
+  "keywords": [],
+  "type": "module",
+  "scripts": {{
+{}
+  }},
+  "dependencies": {{
+    "@modelcontextprotocol/sdk": "^1.11.2",
+    "zod": "^3.24.4"
+  }},
+  "devDependencies": {{
+{}
+  }}
+}}
+"#,
+        entries(scripts),
+        entries(dev_dependencies)
+    )
+}
+
+/// Returns the Dockerfile and .dockerignore used to build the server image.
+fn container_files() -> Vec<DeploymentFile> {
+    vec![
+        DeploymentFile {
+            path: PathBuf::from("Dockerfile"),
+            contents: format!(
+                r#"FROM node:22-alpine
+
+WORKDIR /app
+COPY package.json ./
+RUN npm install
+COPY . .
+RUN npm run build
+
+ENV PORT={0}
+EXPOSE {0}
+CMD ["node", "dist/index.js"]
+"#,
+                CONTAINER_PORT
+            ),
+        },
+        DeploymentFile {
+            path: PathBuf::from(".dockerignore"),
+            contents: ".env\ndist\nk8s\nnode_modules\n".to_string(),
+        },
+    ]
+}
+
+/// Returns the Kubernetes Deployment, Service, and optional Ingress manifests.
+///
+/// Environment variables are read from a Secret named `<name>-env`, which can be created from the
+/// server's `.env` file.
+///
+/// # Arguments
+///
+/// * `name` - Name of the deployment, used for all resources and labels.
+/// * `ingress_host` - Host to route to the server, if an Ingress should be emitted.
+fn kubernetes_manifests(name: &str, ingress_host: Option<&str>) -> Vec<DeploymentFile> {
+    let mut files = vec![
+        DeploymentFile {
+            path: PathBuf::from("k8s/deployment.yaml"),
+            contents: format!(
+                r#"# Build the image with `docker build -t {0}:latest .` and create the environment with
+# `kubectl create secret generic {0}-env --from-env-file=.env`.
+apiVersion: apps/v1
+kind: Deployment
+metadata:
+  name: {0}
+  labels:
+    app: {0}
+spec:
+  replicas: 1
+  selector:
+    matchLabels:
+      app: {0}
+  template:
+    metadata:
+      labels:
+        app: {0}
+    spec:
+      containers:
+        - name: {0}
+          image: {0}:latest
+          imagePullPolicy: IfNotPresent
+          ports:
+            - name: http
+              containerPort: {1}
+          env:
+            - name: PORT
+              value: "{1}"
+          envFrom:
+            - secretRef:
+                name: {0}-env
+          readinessProbe:
+            httpGet:
+              path: /healthz
+              port: http
+          livenessProbe:
+            httpGet:
+              path: /healthz
+              port: http
+"#,
+                name, CONTAINER_PORT
+            ),
+        },
+        DeploymentFile {
+            path: PathBuf::from("k8s/service.yaml"),
+            contents: format!(
+                r#"apiVersion: v1
+kind: Service
+metadata:
+  name: {0}
+  labels:
+    app: {0}
+spec:
+  selector:
+    app: {0}
+  ports:
+    - name: http
+      port: 80
+      targetPort: http
+"#,
+                name
+            ),
+        },
+    ];
+
+    if let Some(host) = ingress_host {
+        files.push(DeploymentFile {
+            path: PathBuf::from("k8s/ingress.yaml"),
+            contents: format!(
+                r#"apiVersion: networking.k8s.io/v1
+kind: Ingress
+metadata:
+  name: {0}
+  labels:
+    app: {0}
+spec:
+  rules:
+    - host: {1}
+      http:
+        paths:
+          - path: /mcp
+            pathType: Prefix
+            backend:
+              service:
+                name: {0}
+                port:
+                  name: http
+"#,
+                name, host
+            ),
+        });
+    }
+
+    files
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::options::Transport;
+
+    #[test]
+    fn test_app_name() {
+        assert_eq!(app_name(Path::new("out/Users_API")), "users-api");
+        assert_eq!(app_name(Path::new("out/--mcp--")), "mcp");
+        assert_eq!(app_name(Path::new("/")), DEFAULT_APP_NAME);
+    }
+
+    #[test]
+    fn test_deployment_files_for_k8s() {
+        let options = GeneratorOptions {
+            transport: Some(Transport::Http),
+            k8s: true,
+            k8s_ingress_host: Some("mcp.example.com".to_string()),
+            ..GeneratorOptions::default()
+        };
+
+        let files = deployment_files(Path::new("servers/users"), &options);
+        let paths = files
+            .iter()
+            .map(|file| file.path.to_string_lossy().into_owned())
+            .collect::<Vec<_>>();
+        let deployment = &files[2].contents;
+
+        assert_eq!(
+            paths,
+            vec![
+                "Dockerfile",
+                ".dockerignore",
+                "k8s/deployment.yaml",
+                "k8s/service.yaml",
+                "k8s/ingress.yaml"
+            ]
+        );
+        assert!(deployment.contains("                name: users-env"));
+        assert!(deployment.contains("containerPort: 8080"));
+        assert!(files[4].contents.contains("- host: mcp.example.com"));
+    }
+
+    #[test]
+    fn test_deployment_files_for_cloudflare_workers() {
+        let options = GeneratorOptions {
+            runtime: Runtime::CloudflareWorkers,
+            ..GeneratorOptions::default()
+        };
+
+        let files = deployment_files(Path::new("servers/users"), &options);
+
+        assert_eq!(files[0].path, PathBuf::from("wrangler.toml"));
+        assert!(files[0].contents.contains("name = \"users\""));
+        assert!(
+            files[1]
+                .contents
+                .contains("\"deploy\": \"wrangler deploy\"")
+        );
+        assert!(!files[1].contents.contains("dotenv"));
+    }
+
+    #[test]
+    fn test_deployment_files_for_aws_lambda() {
+        let options = GeneratorOptions {
+            runtime: Runtime::AwsLambda,
+            ..GeneratorOptions::default()
+        };
+
+        let files = deployment_files(Path::new("servers/users"), &options);
+
+        assert_eq!(files[0].path, PathBuf::from("template.yaml"));
+        assert!(files[0].contents.contains("Handler: index.handler"));
+        assert!(files[0].contents.contains("BACKEND_URL: !Ref BackendUrl"));
+        assert!(
+            files[0]
+                .contents
+                .contains("BACKEND_ACCEPT_LANGUAGE: !Ref BackendAcceptLanguage")
+        );
+        assert!(
+            files[0]
+                .contents
+                .contains("ENABLE_PRIVILEGED_TOOLS: !Ref EnablePrivilegedTools")
+        );
+        assert!(files[0].contents.contains("Path: /healthz"));
+        assert!(
+            files[1]
+                .contents
+                .contains("    \"@types/aws-lambda\": \"^8.10.149\",")
+        );
+        assert!(serde_json::from_str::<serde_json::Value>(&files[1].contents).is_ok());
+    }
+
+    #[test]
+    fn test_deployment_files_without_target() {
+        let files = deployment_files(Path::new("servers/users"), &GeneratorOptions::default());
+
+        assert!(files.is_empty());
+    }
+}
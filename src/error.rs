@@ -1,3 +1,5 @@
+use std::collections::HashSet;
+use std::fmt;
 use std::path::{PathBuf, StripPrefixError};
 use thiserror::Error;
 
@@ -47,6 +49,108 @@ pub enum OpenApiToMcpError {
     /// Failed to write the index.ts file.
     #[error("Failed to write index.ts")]
     IndexFileWrite,
+
+    /// One or more operations could not be generated.
+    #[error("{0}")]
+    Generation(GenerationReport),
+
+    /// A resource polling interval outside the range a JavaScript timer can wait, which would
+    /// poll the backend in a tight loop.
+    #[error("--poll-interval must be between 1 and {max} seconds, got {0}", max = crate::options::MAX_POLL_INTERVAL_SECONDS)]
+    PollIntervalOutOfRange(u64),
+
+    /// A resource polling interval was given without requesting subscribable resources.
+    #[error("--poll-interval requires subscribable resources (--watch-resources)")]
+    PollIntervalRequiresWatchResources,
+
+    /// An option that only applies to the Node.js runtime was combined with another runtime.
+    #[error("{0} is only supported with the Node.js runtime (--runtime node)")]
+    UnsupportedRuntimeOption(&'static str),
+
+    /// Subscribable resources were requested for a server that does not keep client connections.
+    #[error("--watch-resources requires the stdio transport (--transport stdio)")]
+    WatchResourcesRequiresStdio,
+
+    /// Kubernetes manifests were requested for a server that does not use the HTTP transport.
+    #[error("Kubernetes manifests require the HTTP transport (--transport http)")]
+    K8sRequiresHttpTransport,
+
+    /// A Kubernetes Ingress host was given without requesting Kubernetes manifests.
+    #[error("A Kubernetes Ingress host requires Kubernetes manifests (--k8s)")]
+    K8sIngressRequiresK8s,
+
+    /// Failed to write a deployment file.
+    #[error("Failed to write deployment file: {0}")]
+    DeploymentFileWrite(PathBuf),
+
+    /// Failed to read the workspace config file.
+    #[error("Failed to read workspace config: {0}")]
+    WorkspaceConfigRead(PathBuf),
+
+    /// Failed to parse the workspace config file.
+    #[error("Failed to parse workspace config {0}: {1}")]
+    WorkspaceConfigParse(PathBuf, String),
+
+    /// The workspace config does not list any servers.
+    #[error("Workspace config lists no servers: {0}")]
+    WorkspaceEmpty(PathBuf),
+
+    /// Several servers in the workspace config write to the same output directory.
+    #[error("Workspace config lists output directory {0} more than once")]
+    WorkspaceDuplicateOutput(PathBuf),
+
+    /// One or more servers in the workspace failed to generate.
+    #[error("Failed to generate {failed} of {total} workspace servers")]
+    WorkspaceGeneration { failed: usize, total: usize },
+}
+
+/// A problem that prevented a single operation from being generated.
+#[derive(Debug)]
+pub struct GenerationIssue {
+    /// JSON pointer to the operation in the OpenAPI spec (e.g., `#/paths/~1users/post`).
+    pub pointer: String,
+
+    /// The method, path, and identifier of the operation (e.g., `POST /users (createUser)`).
+    pub operation: String,
+
+    /// Description of the problem.
+    pub message: String,
+}
+
+/// All problems found while generating a server, reported together once generation has finished.
+#[derive(Debug)]
+pub struct GenerationReport {
+    /// The problems found, in spec order.
+    pub issues: Vec<GenerationIssue>,
+
+    /// The number of operations in the spec.
+    pub total: usize,
+}
+
+impl fmt::Display for GenerationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let failed = self
+            .issues
+            .iter()
+            .map(|issue| &issue.pointer)
+            .collect::<HashSet<_>>()
+            .len();
+        write!(
+            f,
+            "Failed to generate {} of {} operations ({} issues):",
+            failed,
+            self.total,
+            self.issues.len()
+        )?;
+        for issue in &self.issues {
+            write!(
+                f,
+                "\n  - {} at {}: {}",
+                issue.operation, issue.pointer, issue.message
+            )?;
+        }
+        Ok(())
+    }
 }
 
 /// A type alias for `Result<T, OpenApiToMcpError>`.
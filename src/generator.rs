@@ -1,22 +1,131 @@
 use serde_json::Value;
+use std::collections::{HashMap, HashSet};
+
+use crate::error::{GenerationIssue, GenerationReport, OpenApiToMcpError, Result};
+use crate::options::{GeneratorOptions, Runtime, Transport};
+
+/// Environment variables the generated server needs before it can reach the backend.
+const REQUIRED_CONFIG: &[&str] = &["BACKEND_URL"];
+
+/// HTTP methods that identify operations in an OpenAPI path item.
+const HTTP_METHODS: &[&str] = &[
+    "get", "put", "post", "delete", "options", "head", "patch", "trace",
+];
+
+/// TypeScript code constructing the MCP server.
+const SERVER_CONSTRUCTION: &str = r#"
+const server = new McpServer({
+  name: "Generated-MCP",
+  version: "1.0.0",
+});
+"#;
+
+/// TypeScript imports used by the web-standard request handler of serverless runtimes.
+const WEB_HANDLER_IMPORTS: &str = r#"import type { Transport } from "@modelcontextprotocol/sdk/shared/transport.js";
+import {
+  isJSONRPCError,
+  isJSONRPCRequest,
+  isJSONRPCResponse,
+  type JSONRPCMessage,
+} from "@modelcontextprotocol/sdk/types.js";
+"#;
+
+/// Path segments and tags that mark an endpoint as an event or notification feed.
+const WATCHABLE_NAMES: &[&str] = &["events", "notifications"];
+
+/// Zod schema for the operations of a JSON Patch (RFC 6902) document.
+const JSON_PATCH_SCHEMA: &str = r#"z.array(z.object({ op: z.enum(["add", "remove", "replace", "move", "copy", "test"]), path: z.string(), value: z.any().optional(), from: z.string().optional() }))"#;
+
+/// The request body formats the generator supports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BodyFormat {
+    /// A plain JSON object, sent as `application/json`.
+    Json,
+    /// A JSON Merge Patch (RFC 7396) document, sent as `application/merge-patch+json`.
+    MergePatch,
+    /// A JSON Patch (RFC 6902) array of operations, sent as `application/json-patch+json`.
+    JsonPatch,
+}
+
+impl BodyFormat {
+    /// Supported formats in order of preference, when an operation accepts several.
+    const ALL: [BodyFormat; 3] = [Self::Json, Self::MergePatch, Self::JsonPatch];
+
+    /// Returns the media type of the format.
+    fn content_type(self) -> &'static str {
+        match self {
+            Self::Json => "application/json",
+            Self::MergePatch => "application/merge-patch+json",
+            Self::JsonPatch => "application/json-patch+json",
+        }
+    }
+}
+
+/// An operation ranked by how useful it is as a tool.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RankedTool {
+    /// JSON pointer to the operation in the specification.
+    pub pointer: String,
+    /// The method, path, and tool name of the operation.
+    pub operation: String,
+    /// The usefulness score of the operation, higher meaning more useful.
+    pub score: i64,
+}
 
 pub struct CodeGenerator {
     openapi: Value,
+    options: GeneratorOptions,
 }
 
 impl CodeGenerator {
-    pub fn new(openapi: Value) -> Self {
-        Self { openapi }
+    pub fn new(openapi: Value, options: GeneratorOptions) -> Self {
+        Self { openapi, options }
     }
 
-    pub fn generate(&self) -> String {
+    /// Generates the TypeScript code of the MCP server.
+    ///
+    /// Operations that cannot be generated do not stop generation. Every problem is collected and
+    /// reported together, so a spec can be fixed in one pass.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<String>` - The generated code, or a report of every operation that failed to generate.
+    pub fn generate(&self) -> Result<String> {
         let mut code = String::new();
+        let mut issues = Vec::new();
 
         self.add_imports(&mut code);
-        self.generate_tools(&mut code);
+        self.add_config_check(&mut code);
+
+        let mut registrations = String::new();
+        self.add_config_status_tool(&mut registrations);
+        let total = self.generate_tools(&mut registrations, &mut issues);
+        if self.options.watch_resources {
+            self.generate_watched_resources(&mut registrations);
+        }
+
+        if self.options.server_per_request() {
+            // Stateless requests may run concurrently, so each gets a server of its own.
+            code.push_str(
+                "\n/**\n * Creates an MCP server with all tools registered.\n */\nfunction createServer(): McpServer {\n",
+            );
+            code.push_str(&indent(SERVER_CONSTRUCTION.trim_start_matches('\n'), "  "));
+            code.push_str(&indent(&registrations, "  "));
+            code.push_str("\n  return server;\n}\n");
+        } else {
+            code.push_str(&registrations);
+        }
+
         self.add_server_connection(&mut code);
 
-        code
+        if !issues.is_empty() {
+            return Err(OpenApiToMcpError::Generation(GenerationReport {
+                issues,
+                total,
+            }));
+        }
+
+        Ok(code)
     }
 
     /// Appends the TypeScript import statements and initialization code to the provided string.
@@ -35,29 +144,270 @@ impl CodeGenerator {
  */
 
 import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
-import { StdioServerTransport } from "@modelcontextprotocol/sdk/server/stdio.js";
-import dotenv from "dotenv";
-import { z } from "zod";
+"#,
+        );
+        match self.options.runtime {
+            Runtime::Node => {
+                match self.options.transport() {
+                    Transport::Stdio => code.push_str(
+                        "import { StdioServerTransport } from \"@modelcontextprotocol/sdk/server/stdio.js\";\n",
+                    ),
+                    Transport::Http => code.push_str(
+                        "import { StreamableHTTPServerTransport } from \"@modelcontextprotocol/sdk/server/streamableHttp.js\";\n",
+                    ),
+                }
+                if self.options.watch_resources {
+                    code.push_str(
+                        "import {\n  SubscribeRequestSchema,\n  UnsubscribeRequestSchema,\n} from \"@modelcontextprotocol/sdk/types.js\";\n",
+                    );
+                }
+                code.push_str("import dotenv from \"dotenv\";\n");
+                if self.options.transport() == Transport::Http {
+                    code.push_str("import http from \"node:http\";\n");
+                }
+                code.push_str(
+                    r#"import { z } from "zod";
 
 dotenv.config();
 
 /**
- * Calls the backend REST API.
+ * Environment variables configuring the server.
+ */
+const env: Record<string, string | undefined> = process.env;
+"#,
+                );
+            }
+            Runtime::CloudflareWorkers => {
+                code.push_str(WEB_HANDLER_IMPORTS);
+                code.push_str(
+                    r#"import { env as bindings } from "cloudflare:workers";
+import { z } from "zod";
+
+/**
+ * Environment variables configuring the server, read from the Worker's bindings.
+ */
+const env = bindings as unknown as Record<string, string | undefined>;
+"#,
+                );
+            }
+            Runtime::AwsLambda => {
+                code.push_str(WEB_HANDLER_IMPORTS);
+                code.push_str(
+                    r#"import type { APIGatewayProxyEventV2, APIGatewayProxyStructuredResultV2 } from "aws-lambda";
+import { z } from "zod";
+
+/**
+ * Environment variables configuring the server, set on the Lambda function.
+ */
+const env: Record<string, string | undefined> = process.env;
+"#,
+                );
+            }
+        }
+        if self.options.debug_curl {
+            code.push_str(
+                r#"
+/**
+ * Matches header, query parameter, and body property names whose values are redacted from curl
+ * commands.
+ */
+const SENSITIVE_NAME = /authorization|cookie|token|secret|password|api[-_]?key/i;
+
+/**
+ * Redacts the values of sensitive properties in a JSON value, including nested ones.
+ */
+function redactJson(value: unknown): unknown {
+  if (Array.isArray(value)) return value.map(redactJson);
+  if (value !== null && typeof value === "object") {
+    return Object.fromEntries(
+      Object.entries(value).map(([key, item]) => [key, SENSITIVE_NAME.test(key) ? "REDACTED" : redactJson(item)])
+    );
+  }
+  return value;
+}
+
+/**
+ * Quotes a value for use in a POSIX shell command.
+ */
+function shellQuote(value: string): string {
+  return `'${value.replace(/'/g, `'\\''`)}'`;
+}
+
+/**
+ * Builds the curl command equivalent to a backend call, with secrets redacted.
  */
-async function callBackend<T>(path: string, options?: RequestInit): Promise<T> {
-  const baseUrl = process.env.BACKEND_URL;
-  const url = `${baseUrl}${path}`;
-  const res = await fetch(url, options);
-  if (!res.ok) {
-    throw new Error(`Backend error: ${res.status} ${res.statusText}`);
+function toCurl(url: string, method: string, headers: Headers, body?: BodyInit | null): string {
+  let redactedUrl = url;
+  try {
+    const parsed = new URL(url);
+    parsed.username = "";
+    parsed.password = "";
+    for (const name of [...parsed.searchParams.keys()]) {
+      if (SENSITIVE_NAME.test(name)) parsed.searchParams.set(name, "REDACTED");
+    }
+    redactedUrl = parsed.toString();
+  } catch {
+    // Keep the URL as is when it cannot be parsed.
   }
+
+  const parts = ["curl", "-X", method, shellQuote(redactedUrl)];
+  headers.forEach((value, name) => {
+    parts.push("-H", shellQuote(`${name}: ${SENSITIVE_NAME.test(name) ? "REDACTED" : value}`));
+  });
+  if (typeof body === "string") {
+    let redactedBody = body;
+    try {
+      redactedBody = JSON.stringify(redactJson(JSON.parse(body)));
+    } catch {
+      // Keep the body as is when it is not JSON.
+    }
+    parts.push("--data-raw", shellQuote(redactedBody));
+  }
+  return parts.join(" ");
+}
+"#,
+            );
+        }
+        let (trace_param, trace_call) = if self.options.debug_curl {
+            (
+                ", trace?: string[]",
+                "  trace?.push(toCurl(url, options?.method ?? \"GET\", headers, options?.body));\n",
+            )
+        } else {
+            ("", "")
+        };
+        code.push_str(&format!(
+            r#"
+/**
+ * Calls the backend REST API.
+ */
+async function callBackend<T>(path: string, options?: RequestInit{}): Promise<T> {{
+  const baseUrl = env.BACKEND_URL;
+  const url = `${{baseUrl}}${{path}}`;
+  const headers = new Headers(options?.headers);
+  const acceptLanguage = env.BACKEND_ACCEPT_LANGUAGE;
+  if (acceptLanguage && !headers.has("Accept-Language")) {{
+    headers.set("Accept-Language", acceptLanguage);
+  }}
+{}  const res = await fetch(url, {{ ...options, headers }});
+  if (!res.ok) {{
+    throw new Error(`Backend error: ${{res.status}} ${{res.statusText}}`);
+  }}
   return res.json();
+}}
+"#,
+            trace_param, trace_call
+        ));
+        if self.options.camel_case {
+            code.push_str(
+                r#"
+/**
+ * Renames tool parameters to the property names the backend expects.
+ */
+function renameKeys(
+  params: Record<string, unknown>,
+  names: Record<string, string>
+): Record<string, unknown> {
+  return Object.fromEntries(
+    Object.entries(params).map(([key, value]) => [names[key] ?? key, value])
+  );
 }
+"#,
+            );
+        }
+        // Stateless servers construct a server per request instead, see `generate`.
+        if !self.options.server_per_request() {
+            code.push_str(SERVER_CONSTRUCTION);
+        }
+    }
 
-const server = new McpServer({
-  name: "Generated-MCP",
-  version: "1.0.0",
-});
+    /// Appends the TypeScript code that validates the required configuration at startup.
+    ///
+    /// Missing configuration does not stop the server from connecting to the MCP client. Instead,
+    /// it is logged to stderr and every generated tool responds with a configuration error until
+    /// the environment is fixed, rather than calling the backend with a broken URL.
+    ///
+    /// # Arguments
+    ///
+    /// * `code` - A mutable reference to the string where the generated TypeScript code will be appended.
+    fn add_config_check(&self, code: &mut String) {
+        let required = REQUIRED_CONFIG
+            .iter()
+            .map(|name| format!("\"{}\"", name))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        code.push_str(&format!(
+            r#"
+/**
+ * Environment variables required to reach the backend.
+ */
+const REQUIRED_CONFIG = [{}];
+const missingConfig = REQUIRED_CONFIG.filter((name) => !env[name]);
+
+/**
+ * Whether tools for operations marked `x-mcp-privileged` are registered.
+ */
+const privilegedToolsEnabled = env.ENABLE_PRIVILEGED_TOOLS === "1";
+
+if (missingConfig.length > 0) {{
+  console.error(
+    `Missing required configuration: ${{missingConfig.join(", ")}}. Tool calls will fail until it is set.`
+  );
+}}
+
+/**
+ * Builds the tool result returned when required configuration is missing.
+ */
+function configErrorResult() {{
+  return {{
+    content: [
+      {{
+        type: "text" as const,
+        text: `Configuration error: missing ${{missingConfig.join(", ")}}. Set it in the server environment and restart the server.`,
+      }},
+    ],
+    isError: true,
+  }};
+}}
+"#,
+            required
+        ));
+    }
+
+    /// Appends the `__config_status` tool, which reports which required configuration is set.
+    ///
+    /// Only the names of the variables are reported, never their values.
+    ///
+    /// # Arguments
+    ///
+    /// * `code` - A mutable reference to the string where the generated TypeScript code will be appended.
+    fn add_config_status_tool(&self, code: &mut String) {
+        code.push_str(
+            r#"
+server.tool(
+  "__config_status",
+  {},
+  async () => {
+    return {
+      content: [
+        {
+          type: "text",
+          text: JSON.stringify(
+            {
+              ok: missingConfig.length === 0,
+              required: REQUIRED_CONFIG,
+              missing: missingConfig,
+              privilegedToolsEnabled,
+            },
+            null,
+            2
+          ),
+        },
+      ],
+    };
+  }
+);
 "#,
         );
     }
@@ -66,19 +416,68 @@ const server = new McpServer({
     ///
     /// This function iterates over all paths and HTTP methods in the OpenAPI document and generates
     /// corresponding TypeScript server tool code for each operation. The generated code is appended
-    /// to the provided `code` string.
+    /// to the provided `code` string. Operations that cannot be generated are skipped and recorded
+    /// in `issues`, and operations ranked below the `--max-tools` cap are left out.
     ///
     /// # Arguments
     ///
     /// * `code` - A mutable reference to the string where the generated TypeScript code will be appended.
-    fn generate_tools(&self, code: &mut String) {
-        if let Some(paths) = self.openapi.get("paths") {
-            if let Some(paths_obj) = paths.as_object() {
-                for (path, path_item) in paths_obj {
-                    if let Some(path_item_obj) = path_item.as_object() {
-                        for (method, operation) in path_item_obj {
-                            if let Some(operation_obj) = operation.as_object() {
-                                let operation_value = Value::Object(operation_obj.clone());
+    /// * `issues` - A mutable reference to the vector where generation problems will be added.
+    ///
+    /// # Returns
+    ///
+    /// The number of operations considered, excluding those pruned by `--max-tools`.
+    fn generate_tools(&self, code: &mut String, issues: &mut Vec<GenerationIssue>) -> usize {
+        let mut total = 0;
+        let mut operation_ids = HashSet::from(["__config_status".to_string()]);
+        let pruned: HashSet<String> = self
+            .pruned_tools()
+            .into_iter()
+            .map(|tool| tool.pointer)
+            .collect();
+
+        if let Some(paths) = self.openapi.get("paths")
+            && let Some(paths_obj) = paths.as_object()
+        {
+            for (path, path_item) in paths_obj {
+                if let Some(path_item_obj) = path_item.as_object() {
+                    for (method, operation) in path_item_obj {
+                        if !HTTP_METHODS.contains(&method.as_str()) {
+                            continue;
+                        }
+                        if pruned.contains(&operation_pointer(path, method)) {
+                            continue;
+                        }
+                        if let Some(operation_obj) = operation.as_object() {
+                            total += 1;
+                            let operation_value = Value::Object(operation_obj.clone());
+                            let operation_id = self.operation_id(path, method, &operation_value);
+
+                            let mut problems = self.validate_operation(&operation_value);
+                            if !operation_ids.insert(operation_id.clone()) {
+                                problems.push(format!("duplicate tool name `{}`", operation_id));
+                            }
+                            if !problems.is_empty() {
+                                let pointer = operation_pointer(path, method);
+                                let operation = operation_label(path, method, &operation_id);
+                                issues.extend(problems.into_iter().map(|message| {
+                                    GenerationIssue {
+                                        pointer: pointer.clone(),
+                                        operation: operation.clone(),
+                                        message,
+                                    }
+                                }));
+                                continue;
+                            }
+
+                            if self.is_privileged(&operation_value) {
+                                // Only register privileged tools when the deployment opts in.
+                                let mut tool = String::new();
+                                self.generate_tool(&mut tool, path, method, &operation_value);
+                                code.push_str("\nif (privilegedToolsEnabled) {\n");
+                                code.push_str(&indent(tool.trim_start_matches('\n'), "  "));
+                                code.push_str("}\n");
+                            } else {
                                 self.generate_tool(code, path, method, &operation_value);
                             }
                         }
@@ -86,200 +485,987 @@ const server = new McpServer({
                 }
             }
         }
+
+        total
     }
 
-    /// Generates a TypeScript server tool function for a given OpenAPI operation.
+    /// Returns the operations left out of the generated server by the `--max-tools` cap.
     ///
-    /// This function appends the generated TypeScript code for a server tool to the provided `code` string.
-    /// It uses the specified HTTP path, method, and operation details from the OpenAPI specification.
+    /// Operations are ranked by `tool_score`, keeping the specification order between equal
+    /// scores, and everything below the top `max_tools` is pruned. The config status tool is
+    /// always registered and does not count towards the cap.
     ///
-    /// # Arguments
+    /// # Returns
     ///
-    /// * `code` - A mutable reference to the string where the generated TypeScript code will be appended.
-    /// * `path` - The HTTP path for the operation (e.g., "/agents/{id}").
-    /// * `method` - The HTTP method for the operation (e.g., "get", "post").
-    /// * `operation` - The OpenAPI operation object as a serde_json `Value`.
-    fn generate_tool(&self, code: &mut String, path: &str, method: &str, operation: &Value) {
-        let operation_id = operation
-            .get("operationId")
-            .and_then(|id| id.as_str())
-            .map(String::from)
-            .unwrap_or_else(|| format!("{}_{}", method, path.replace('/', "_")));
+    /// The pruned operations, highest ranked first, or nothing if no cap is set.
+    pub fn pruned_tools(&self) -> Vec<RankedTool> {
+        let Some(max_tools) = self.options.max_tools else {
+            return Vec::new();
+        };
 
-        let params = self.collect_parameters(operation);
-        let has_query_params = params.iter().any(|p| p.contains("query"));
+        let linked = self.linked_operations();
+        let mut ranked = Vec::new();
+        if let Some(paths) = self.openapi.get("paths").and_then(|p| p.as_object()) {
+            for (path, path_item) in paths {
+                let Some(path_item_obj) = path_item.as_object() else {
+                    continue;
+                };
+                for (method, operation) in path_item_obj {
+                    if !HTTP_METHODS.contains(&method.as_str()) || !operation.is_object() {
+                        continue;
+                    }
+                    let operation_id = self.operation_id(path, method, operation);
+                    let pointer = operation_pointer(path, method);
+                    let is_linked = linked.contains(&pointer)
+                        || operation
+                            .get("operationId")
+                            .and_then(|id| id.as_str())
+                            .is_some_and(|id| linked.contains(id));
+                    ranked.push(RankedTool {
+                        operation: operation_label(path, method, &operation_id),
+                        score: self.tool_score(method, operation, is_linked),
+                        pointer,
+                    });
+                }
+            }
+        }
 
-        // Generate tool.
-        code.push_str(&format!(
-            "\nserver.tool(\n  \"{}\",\n  {{\n    {}\n  }},\n  async (params) => {{\n",
-            operation_id,
-            params.join(",\n    ")
-        ));
+        ranked.sort_by_key(|tool| std::cmp::Reverse(tool.score));
+        ranked.into_iter().skip(max_tools).collect()
+    }
 
-        // Add query parameters only if they exist.
-        if has_query_params {
-            code.push_str("    const search = new URLSearchParams();\n");
-            code.push_str("    Object.entries(params).forEach(([key, value]) => {\n");
-            code.push_str("      if (value) search.set(key, String(value));\n");
-            code.push_str("    });\n\n");
-        }
+    /// Returns the operations whose request body has no supported content type.
+    ///
+    /// These operations are not fatal: their tools are generated without a request body, and
+    /// the caller reports them as warnings once generation has finished.
+    ///
+    /// # Returns
+    ///
+    /// A warning for every such operation not pruned by `--max-tools`, in spec order.
+    pub fn unsupported_bodies(&self) -> Vec<GenerationIssue> {
+        let pruned: HashSet<String> = self
+            .pruned_tools()
+            .into_iter()
+            .map(|tool| tool.pointer)
+            .collect();
 
-        // Add API call.
-        let method_upper = method.to_uppercase();
-        let request_options = match method_upper.as_str() {
-            "GET" => "{\n        method: \"GET\"\n      }".to_string(),
-            "POST" | "PUT" | "PATCH" => {
-                format!(
-                    "{{\n        method: \"{}\",\n        headers: {{ \"Content-Type\": \"application/json\" }},\n        body: JSON.stringify(params)\n      }}",
-                    method_upper
-                )
-            }
-            "DELETE" => {
-                if !params.is_empty() {
-                    "{\n        method: \"DELETE\",\n        headers: { \"Content-Type\": \"application/json\" },\n        body: JSON.stringify(params)\n      }".to_string()
-                } else {
-                    "{\n        method: \"DELETE\"\n      }".to_string()
+        let mut warnings = Vec::new();
+        if let Some(paths) = self.openapi.get("paths").and_then(|p| p.as_object()) {
+            for (path, path_item) in paths {
+                let Some(path_item_obj) = path_item.as_object() else {
+                    continue;
+                };
+                for (method, operation) in path_item_obj {
+                    if !HTTP_METHODS.contains(&method.as_str()) || !operation.is_object() {
+                        continue;
+                    }
+                    let pointer = operation_pointer(path, method);
+                    if pruned.contains(&pointer) {
+                        continue;
+                    }
+                    if let Some(content_types) = self.unsupported_content_types(operation) {
+                        let operation_id = self.operation_id(path, method, operation);
+                        warnings.push(GenerationIssue {
+                            pointer,
+                            operation: operation_label(path, method, &operation_id),
+                            message: format!(
+                                "unsupported request body content type `{}`",
+                                content_types.join("`, `")
+                            ),
+                        });
+                    }
                 }
             }
-            _ => {
-                format!("{{\n        method: \"{}\"\n      }}", method_upper)
-            }
-        };
+        }
 
-        code.push_str(&format!(
-            "    const result = await callBackend<any>(\n      \"{}{}\",\n      {}\n    );\n\n",
-            path,
-            if has_query_params {
-                "?${search.toString()}"
-            } else {
-                ""
-            },
-            request_options
-        ));
+        warnings
+    }
 
-        // Add response.
-        code.push_str("    return {\n");
-        code.push_str("      content: [\n");
-        code.push_str("        {\n");
-        code.push_str("          type: \"text\",\n");
-        code.push_str("          text: JSON.stringify(result, null, 2),\n");
-        code.push_str("        },\n");
-        code.push_str("      ],\n");
-        code.push_str("    };\n");
-        code.push_str("  }\n);\n");
+    /// Returns the content types of a request body none of which is a supported format.
+    ///
+    /// # Arguments
+    ///
+    /// * `operation` - The OpenAPI operation object as a serde_json `Value`.
+    ///
+    /// # Returns
+    ///
+    /// The declared content types, or `None` if the operation has no body or a supported one.
+    fn unsupported_content_types<'a>(&self, operation: &'a Value) -> Option<Vec<&'a str>> {
+        let content = operation
+            .get("requestBody")?
+            .get("content")?
+            .as_object()
+            .filter(|content| !content.is_empty())?;
+        self.body_format(operation)
+            .is_none()
+            .then(|| content.keys().map(String::as_str).collect())
     }
 
-    /// Collects the parameters for a given OpenAPI operation and returns them as a vector of strings
-    /// formatted for use with the Zod schema in TypeScript code generation.
+    /// Scores how useful an operation is as a tool, higher meaning more useful.
     ///
-    /// This function inspects the provided OpenAPI operation object and extracts both query parameters
-    /// and request body properties (if present). Query parameters are added as optional strings,
-    /// while request body properties are added as required strings.
+    /// CRUD methods score 2, a `core` tag adds 3, being the target of a link adds 2, and
+    /// deprecation subtracts 3. The integer `x-mcp-priority` extension is added on top, so specs
+    /// can promote or demote operations explicitly.
     ///
     /// # Arguments
     ///
-    /// * `operation` - A reference to a serde_json::Value representing the OpenAPI operation object.
+    /// * `method` - The HTTP method for the operation (e.g., "get").
+    /// * `operation` - The OpenAPI operation object as a serde_json `Value`.
+    /// * `is_linked` - Whether a link in the specification targets the operation.
     ///
     /// # Returns
     ///
-    /// A vector of strings, each representing a parameter definition suitable for use in a Zod schema.
-    fn collect_parameters(&self, operation: &Value) -> Vec<String> {
-        let mut params = Vec::new();
+    /// The score of the operation.
+    fn tool_score(&self, method: &str, operation: &Value, is_linked: bool) -> i64 {
+        let mut score = 0;
+        if matches!(method, "get" | "post" | "put" | "patch" | "delete") {
+            score += 2;
+        }
+        let is_core = operation
+            .get("tags")
+            .and_then(|t| t.as_array())
+            .is_some_and(|tags| {
+                tags.iter()
+                    .filter_map(|tag| tag.as_str())
+                    .any(|tag| tag.eq_ignore_ascii_case("core"))
+            });
+        if is_core {
+            score += 3;
+        }
+        if is_linked {
+            score += 2;
+        }
+        if operation
+            .get("deprecated")
+            .and_then(|d| d.as_bool())
+            .unwrap_or(false)
+        {
+            score -= 3;
+        }
 
-        // Collect query parameters.
-        if let Some(parameters) = operation.get("parameters") {
-            if let Some(params_array) = parameters.as_array() {
-                for param in params_array {
-                    if let Some(param_obj) = param.as_object() {
-                        if let (Some(name), Some(in_)) = (
-                            param_obj.get("name").and_then(|n| n.as_str()),
-                            param_obj.get("in").and_then(|i| i.as_str()),
-                        ) {
-                            if in_ == "query" {
-                                params.push(format!("{}: z.string().optional()", name));
-                            }
-                        }
-                    }
+        score
+            + operation
+                .get("x-mcp-priority")
+                .and_then(|p| p.as_i64())
+                .unwrap_or(0)
+    }
+
+    /// Collects the targets of every link in the specification.
+    ///
+    /// Links are read from operation responses and from `components.links`. A target is either
+    /// the `operationId` of a link, or the JSON pointer of its local `operationRef`.
+    ///
+    /// # Returns
+    ///
+    /// The operation ids and JSON pointers targeted by links.
+    fn linked_operations(&self) -> HashSet<String> {
+        let mut links: Vec<&Value> = Vec::new();
+        if let Some(paths) = self.openapi.get("paths").and_then(|p| p.as_object()) {
+            for (method, operation) in paths
+                .values()
+                .filter_map(|path_item| path_item.as_object())
+                .flatten()
+            {
+                if !HTTP_METHODS.contains(&method.as_str()) {
+                    continue;
+                }
+                if let Some(responses) = operation.get("responses").and_then(|r| r.as_object()) {
+                    links.extend(
+                        responses
+                            .values()
+                            .filter_map(|response| response.get("links"))
+                            .filter_map(|l| l.as_object())
+                            .flat_map(|l| l.values()),
+                    );
                 }
             }
         }
+        if let Some(component_links) = self
+            .openapi
+            .pointer("/components/links")
+            .and_then(|l| l.as_object())
+        {
+            links.extend(component_links.values());
+        }
 
-        // Collect request body parameters.
-        if let Some(request_body) = operation.get("requestBody") {
-            if let Some(content) = request_body.get("content") {
-                if let Some(json) = content.get("application/json") {
-                    if let Some(schema) = json.get("schema") {
-                        if let Some(ref_path) = schema.get("$ref").and_then(|r| r.as_str()) {
-                            // Handle schema reference.
-                            if let Some(components) = self.openapi.get("components") {
-                                if let Some(schemas) = components.get("schemas") {
-                                    if let Some(referenced_schema) = schemas
-                                        .get(ref_path.trim_start_matches("#/components/schemas/"))
-                                    {
-                                        self.process_schema_properties(
-                                            referenced_schema,
-                                            &mut params,
-                                        );
-                                    }
-                                }
-                            }
-                        } else {
-                            self.process_schema_properties(schema, &mut params);
-                        }
-                    }
-                }
+        let mut targets = HashSet::new();
+        for link in links {
+            if let Some(operation_id) = link.get("operationId").and_then(|id| id.as_str()) {
+                targets.insert(operation_id.to_string());
+            }
+            if let Some(operation_ref) = link.get("operationRef").and_then(|r| r.as_str())
+                && operation_ref.starts_with("#/")
+            {
+                targets.insert(operation_ref.replace("%7B", "{").replace("%7D", "}"));
             }
         }
 
-        params
+        targets
     }
 
-    /// Processes the properties of a schema and adds them to the parameters list.
+    /// Checks an operation for constructs the generator cannot handle.
     ///
     /// # Arguments
     ///
-    /// * `schema` - The schema object containing properties to process.
-    /// * `params` - A mutable reference to the vector where parameter definitions will be added.
-    fn process_schema_properties(&self, schema: &Value, params: &mut Vec<String>) {
-        if let Some(properties) = schema.get("properties") {
-            if let Some(props_obj) = properties.as_object() {
-                let required = schema
-                    .get("required")
-                    .and_then(|r| r.as_array())
-                    .map(|arr| arr.iter().filter_map(|v| v.as_str()).collect::<Vec<_>>())
-                    .unwrap_or_default();
-
-                for (prop_name, prop_schema) in props_obj {
-                    let type_def = self.get_type_definition(prop_schema);
-                    let is_required = required.contains(&prop_name.as_str());
-                    let param_def = if is_required {
-                        format!("{}: {}", prop_name, type_def)
-                    } else {
-                        format!("{}: {}.optional()", prop_name, type_def)
-                    };
-                    params.push(param_def);
+    /// * `operation` - The OpenAPI operation object as a serde_json `Value`.
+    ///
+    /// # Returns
+    ///
+    /// A description of every problem found, empty if the operation can be generated.
+    fn validate_operation(&self, operation: &Value) -> Vec<String> {
+        let mut problems = Vec::new();
+
+        // Bodies in other formats are not fatal: their tools are generated without a body and
+        // reported by `unsupported_bodies`.
+        if let Some(media_type) = self.body_format(operation).and_then(|format| {
+            operation
+                .get("requestBody")
+                .and_then(|body| body.get("content"))
+                .and_then(|content| content.get(format.content_type()))
+        }) {
+            if let Some(schema) = media_type.get("schema") {
+                self.check_references(schema, &mut problems);
+            }
+            if let Some(properties) = self
+                .request_body_schema(operation)
+                .and_then(|schema| schema.get("properties"))
+                .and_then(|properties| properties.as_object())
+            {
+                for property in properties.values() {
+                    self.check_references(property, &mut problems);
                 }
             }
         }
+
+        problems
     }
 
-    /// Determines the Zod type definition for a given schema.
+    /// Records a problem for every unresolvable `$ref` in a schema or its array items.
     ///
     /// # Arguments
     ///
-    /// * `schema` - The schema object to determine the type for.
+    /// * `schema` - The schema object to check.
+    /// * `problems` - A mutable reference to the vector where problems will be added.
+    fn check_references(&self, schema: &Value, problems: &mut Vec<String>) {
+        if let Some(ref_path) = schema.get("$ref").and_then(|r| r.as_str())
+            && self.resolve_reference(ref_path).is_none()
+        {
+            problems.push(format!("unresolved reference `{}`", ref_path));
+        }
+        if let Some(items) = schema.get("items") {
+            self.check_references(items, problems);
+        }
+    }
+
+    /// Resolves a local `$ref` (e.g., "#/components/schemas/User") against the OpenAPI document.
+    ///
+    /// # Arguments
+    ///
+    /// * `ref_path` - The reference to resolve.
     ///
     /// # Returns
     ///
-    /// A string representing the Zod type definition.
-    fn get_type_definition(&self, schema: &Value) -> String {
-        match schema.get("type").and_then(|t| t.as_str()) {
-            Some("string") => "z.string()".to_string(),
-            Some("number") => "z.number()".to_string(),
-            Some("integer") => "z.number().int()".to_string(),
-            Some("boolean") => "z.boolean()".to_string(),
-            Some("array") => {
-                if let Some(items) = schema.get("items") {
+    /// The referenced value, or `None` if the reference is external or points nowhere.
+    fn resolve_reference(&self, ref_path: &str) -> Option<&Value> {
+        ref_path
+            .strip_prefix('#')
+            .and_then(|pointer| self.openapi.pointer(pointer))
+    }
+
+    /// Generates a TypeScript server tool function for a given OpenAPI operation.
+    ///
+    /// This function appends the generated TypeScript code for a server tool to the provided `code` string.
+    /// It uses the specified HTTP path, method, and operation details from the OpenAPI specification.
+    ///
+    /// # Arguments
+    ///
+    /// * `code` - A mutable reference to the string where the generated TypeScript code will be appended.
+    /// * `path` - The HTTP path for the operation (e.g., "/agents/{id}").
+    /// * `method` - The HTTP method for the operation (e.g., "get", "post").
+    /// * `operation` - The OpenAPI operation object as a serde_json `Value`.
+    fn generate_tool(&self, code: &mut String, path: &str, method: &str, operation: &Value) {
+        let operation_id = self.operation_id(path, method, operation);
+
+        let params = self.collect_parameters(operation);
+        let query_names = self.query_parameter_names(operation);
+        let has_query_params = !query_names.is_empty();
+        let accepts_language = self.declares_accept_language(operation);
+        let full_description = self.tool_description(operation);
+        let (description, is_truncated) = match &full_description {
+            Some(full) => self.truncate_description(full, &operation_id),
+            None => (String::new(), false),
+        };
+
+        // Generate tool.
+        code.push_str(&format!(
+            "\nserver.tool(\n  \"{}\",\n{}  {{\n    {}\n  }},\n  async ({}) => {{\n",
+            operation_id,
+            if description.is_empty() {
+                String::new()
+            } else {
+                format!("  {},\n", Value::from(description))
+            },
+            params.join(",\n    "),
+            if accepts_language {
+                "{ acceptLanguage, ...params }"
+            } else {
+                "params"
+            }
+        ));
+        code.push_str("    if (missingConfig.length > 0) return configErrorResult();\n\n");
+
+        // Add the declared query parameters only if they exist.
+        if has_query_params {
+            code.push_str("    const search = new URLSearchParams();\n");
+            code.push_str(&format!(
+                "    for (const key of {}) {{\n",
+                Value::from(query_names)
+            ));
+            code.push_str("      const value = params[key];\n");
+            code.push_str("      if (value) search.set(key, String(value));\n");
+            code.push_str("    }\n\n");
+        }
+
+        // Add API call.
+        let method_upper = method.to_uppercase();
+        // Never send a body in a format the backend did not declare.
+        let has_body = self.unsupported_content_types(operation).is_none()
+            && match method_upper.as_str() {
+                "POST" | "PUT" | "PATCH" => true,
+                "DELETE" => params.len() > usize::from(accepts_language),
+                _ => false,
+            };
+
+        let body_format = self.body_format(operation).unwrap_or(BodyFormat::Json);
+
+        let mut headers = Vec::new();
+        if has_body {
+            headers.push(format!(
+                "\"Content-Type\": \"{}\"",
+                body_format.content_type()
+            ));
+        }
+        if accepts_language {
+            headers.push(
+                "...(acceptLanguage ? { \"Accept-Language\": acceptLanguage } : {})".to_string(),
+            );
+        }
+
+        let mut options = vec![format!("method: \"{}\"", method_upper)];
+        if !headers.is_empty() {
+            options.push(format!("headers: {{ {} }}", headers.join(", ")));
+        }
+        if has_body {
+            let mapping = self.body_parameter_names(operation);
+            if body_format == BodyFormat::JsonPatch {
+                options.push("body: JSON.stringify(params.patch)".to_string());
+            } else if mapping.is_empty() {
+                options.push("body: JSON.stringify(params)".to_string());
+            } else {
+                let names = mapping
+                    .iter()
+                    .map(|(property, param_name)| {
+                        format!("{}: {}", param_name, Value::from(property.as_str()))
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                options.push(format!(
+                    "body: JSON.stringify(renameKeys(params, {{ {} }}))",
+                    names
+                ));
+            }
+        }
+        let request_options = format!("{{\n        {}\n      }}", options.join(",\n        "));
+
+        let mut call = String::new();
+        call.push_str(&format!(
+            "    const result = await callBackend<any>(\n      \"{}{}\",\n      {}{}\n    );\n\n",
+            path,
+            if has_query_params {
+                "?${search.toString()}"
+            } else {
+                ""
+            },
+            request_options,
+            if self.options.debug_curl {
+                ",\n      curl"
+            } else {
+                ""
+            }
+        ));
+
+        // Add response.
+        call.push_str("    return {\n");
+        call.push_str("      content: [\n");
+        call.push_str("        {\n");
+        call.push_str("          type: \"text\",\n");
+        call.push_str("          text: JSON.stringify(result, null, 2),\n");
+        call.push_str("        },\n");
+        call.push_str("      ],\n");
+        if self.options.debug_curl {
+            call.push_str("      _meta: { curl },\n");
+        }
+        call.push_str("    };\n");
+
+        // Echo the curl commands of failed calls too, since those are the ones worth debugging.
+        if self.options.debug_curl {
+            code.push_str("    const curl: string[] = [];\n");
+            code.push_str("    try {\n");
+            code.push_str(&indent(&call, "  "));
+            code.push_str("    } catch (error) {\n");
+            code.push_str("      return {\n");
+            code.push_str("        content: [{ type: \"text\", text: String(error) }],\n");
+            code.push_str("        isError: true,\n");
+            code.push_str("        _meta: { curl },\n");
+            code.push_str("      };\n");
+            code.push_str("    }\n");
+        } else {
+            code.push_str(&call);
+        }
+        code.push_str("  }\n);\n");
+
+        // Serve the full description of truncated tools as a resource.
+        if is_truncated && let Some(full) = full_description {
+            code.push_str(&format!(
+                r#"
+server.resource(
+  "{0} docs",
+  "api-docs://{0}",
+  {{ mimeType: "text/markdown" }},
+  async (uri) => ({{
+    contents: [
+      {{
+        uri: uri.href,
+        mimeType: "text/markdown",
+        text: {1},
+      }},
+    ],
+  }})
+);
+"#,
+                operation_id,
+                Value::from(full)
+            ));
+        }
+    }
+
+    /// Returns the description of a tool, combining the summary and description of the operation.
+    ///
+    /// # Arguments
+    ///
+    /// * `operation` - The OpenAPI operation object as a serde_json `Value`.
+    ///
+    /// # Returns
+    ///
+    /// The tool description, or `None` if the operation has neither a summary nor a description.
+    fn tool_description(&self, operation: &Value) -> Option<String> {
+        let parts = ["summary", "description"]
+            .iter()
+            .filter_map(|key| operation.get(key).and_then(|v| v.as_str()))
+            .map(str::trim)
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<_>>();
+
+        match parts.as_slice() {
+            [] => None,
+            [summary, description] if summary == description => Some(summary.to_string()),
+            _ => Some(parts.join("\n\n")),
+        }
+    }
+
+    /// Truncates a tool description to the configured maximum length.
+    ///
+    /// Truncated descriptions end with a pointer to the `api-docs://<operationId>` resource that
+    /// serves the full description.
+    ///
+    /// # Arguments
+    ///
+    /// * `description` - The full tool description.
+    /// * `operation_id` - The identifier of the operation the description belongs to.
+    ///
+    /// # Returns
+    ///
+    /// The description to use for the tool, and whether it was truncated.
+    fn truncate_description(&self, description: &str, operation_id: &str) -> (String, bool) {
+        match self.options.max_description_length {
+            Some(max_length) if description.chars().count() > max_length => {
+                let truncated = description.chars().take(max_length).collect::<String>();
+                (
+                    format!(
+                        "{}… (full docs available via resource api-docs://{})",
+                        truncated.trim_end(),
+                        operation_id
+                    ),
+                    true,
+                )
+            }
+            _ => (description.to_string(), false),
+        }
+    }
+
+    /// Returns the identifier of an operation, derived from its method and path when the
+    /// OpenAPI operation has no `operationId`.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The HTTP path for the operation (e.g., "/agents/{id}").
+    /// * `method` - The HTTP method for the operation (e.g., "get", "post").
+    /// * `operation` - The OpenAPI operation object as a serde_json `Value`.
+    ///
+    /// # Returns
+    ///
+    /// The operation identifier, used as the tool name.
+    fn operation_id(&self, path: &str, method: &str, operation: &Value) -> String {
+        operation
+            .get("operationId")
+            .and_then(|id| id.as_str())
+            .map(String::from)
+            .unwrap_or_else(|| format!("{}_{}", method, path.replace('/', "_")))
+    }
+
+    /// Determines whether an operation is marked as privileged with the `x-mcp-privileged` extension.
+    ///
+    /// # Arguments
+    ///
+    /// * `operation` - The OpenAPI operation object as a serde_json `Value`.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the tool should only be registered when `ENABLE_PRIVILEGED_TOOLS=1` is set.
+    fn is_privileged(&self, operation: &Value) -> bool {
+        operation
+            .get("x-mcp-privileged")
+            .and_then(|p| p.as_bool())
+            .unwrap_or(false)
+    }
+
+    /// Determines whether an operation declares an `Accept-Language` header parameter.
+    ///
+    /// # Arguments
+    ///
+    /// * `operation` - The OpenAPI operation object as a serde_json `Value`.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the tool should accept an optional `acceptLanguage` parameter.
+    fn declares_accept_language(&self, operation: &Value) -> bool {
+        operation
+            .get("parameters")
+            .and_then(|p| p.as_array())
+            .is_some_and(|parameters| {
+                parameters.iter().any(|param| {
+                    param.get("in").and_then(|i| i.as_str()) == Some("header")
+                        && param
+                            .get("name")
+                            .and_then(|n| n.as_str())
+                            .is_some_and(|name| name.eq_ignore_ascii_case("accept-language"))
+                })
+            })
+    }
+
+    /// Determines whether an operation is an event or notification feed that clients can watch.
+    ///
+    /// An operation is watchable when it is a `GET` without path parameters and either sets the
+    /// `x-mcp-watch` extension to `true`, or is tagged or named `events` or `notifications`.
+    /// Setting `x-mcp-watch` to `false` opts an operation out.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The HTTP path for the operation (e.g., "/events").
+    /// * `method` - The HTTP method for the operation (e.g., "get").
+    /// * `operation` - The OpenAPI operation object as a serde_json `Value`.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the operation should be exposed as a subscribable resource.
+    fn is_watchable(&self, path: &str, method: &str, operation: &Value) -> bool {
+        if method != "get" || path.contains('{') {
+            return false;
+        }
+
+        if let Some(watch) = operation.get("x-mcp-watch").and_then(|w| w.as_bool()) {
+            return watch;
+        }
+
+        let has_watchable_tag = operation
+            .get("tags")
+            .and_then(|t| t.as_array())
+            .is_some_and(|tags| {
+                tags.iter()
+                    .filter_map(|tag| tag.as_str())
+                    .any(|tag| WATCHABLE_NAMES.contains(&tag.to_lowercase().as_str()))
+            });
+        let has_watchable_segment = path
+            .rsplit('/')
+            .next()
+            .is_some_and(|segment| WATCHABLE_NAMES.contains(&segment.to_lowercase().as_str()));
+
+        has_watchable_tag || has_watchable_segment
+    }
+
+    /// Generates subscribable MCP resources for all event and notification endpoints.
+    ///
+    /// Each watchable operation becomes an `events://<operationId>` resource. When a client
+    /// subscribes, the generated server polls the endpoint on an interval and sends a
+    /// `notifications/resources/updated` notification whenever the response changes.
+    ///
+    /// # Arguments
+    ///
+    /// * `code` - A mutable reference to the string where the generated TypeScript code will be appended.
+    fn generate_watched_resources(&self, code: &mut String) {
+        let mut watched = Vec::new();
+        if let Some(paths) = self.openapi.get("paths")
+            && let Some(paths_obj) = paths.as_object()
+        {
+            for (path, path_item) in paths_obj {
+                if let Some(operation) = path_item.get("get")
+                    && self.is_watchable(path, "get", operation)
+                {
+                    watched.push((
+                        self.operation_id(path, "get", operation),
+                        path.clone(),
+                        self.is_privileged(operation),
+                    ));
+                }
+            }
+        }
+
+        if watched.is_empty() {
+            return;
+        }
+
+        let entries = watched
+            .iter()
+            .filter(|(_, _, privileged)| !privileged)
+            .map(|(operation_id, path, _)| {
+                format!("  \"events://{}\": \"{}\",", operation_id, path)
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        code.push_str(&format!(
+            r#"
+/**
+ * Backend endpoints exposed as subscribable resources, keyed by resource URI.
+ */
+const watchedResources: Record<string, string> = {{
+{}
+}};
+const POLL_INTERVAL_MS = {};
+const subscriptions = new Map<string, ReturnType<typeof setInterval>>();
+
+server.server.registerCapabilities({{ resources: {{ subscribe: true }} }});
+"#,
+            entries,
+            self.options.poll_interval_seconds().saturating_mul(1000)
+        ));
+
+        for (operation_id, path, privileged) in &watched {
+            let mut resource = String::new();
+            if *privileged {
+                resource.push_str(&format!(
+                    "watchedResources[\"events://{}\"] = \"{}\";\n",
+                    operation_id, path
+                ));
+            }
+            resource.push_str(&format!(
+                r#"
+server.resource(
+  "{0}",
+  "events://{0}",
+  {{ mimeType: "application/json" }},
+  async (uri) => {{
+    if (missingConfig.length > 0) {{
+      throw new Error(`Configuration error: missing ${{missingConfig.join(", ")}}`);
+    }}
+
+    const result = await callBackend<any>("{1}", {{ method: "GET" }});
+
+    return {{
+      contents: [
+        {{
+          uri: uri.href,
+          mimeType: "application/json",
+          text: JSON.stringify(result, null, 2),
+        }},
+      ],
+    }};
+  }}
+);
+"#,
+                operation_id, path
+            ));
+
+            if *privileged {
+                // Privileged feeds can neither be read nor subscribed to unless the deployment opts in.
+                code.push_str("\nif (privilegedToolsEnabled) {\n");
+                code.push_str(&indent(&resource, "  "));
+                code.push_str("}\n");
+            } else {
+                code.push_str(&resource);
+            }
+        }
+
+        code.push_str(
+            r#"
+server.server.setRequestHandler(SubscribeRequestSchema, async (request) => {
+  const uri = request.params.uri;
+  const path = watchedResources[uri];
+  if (!path) {
+    throw new Error(`Unknown resource: ${uri}`);
+  }
+  if (subscriptions.has(uri)) {
+    return {};
+  }
+
+  let previous: string | undefined;
+  const poll = async () => {
+    if (missingConfig.length > 0) return;
+    try {
+      const current = JSON.stringify(await callBackend<any>(path, { method: "GET" }));
+      if (previous !== undefined && current !== previous) {
+        await server.server.sendResourceUpdated({ uri });
+      }
+      previous = current;
+    } catch (error) {
+      console.error(`Failed to poll ${uri}: ${error}`);
+    }
+  };
+
+  await poll();
+  subscriptions.set(uri, setInterval(poll, POLL_INTERVAL_MS));
+  return {};
+});
+
+server.server.setRequestHandler(UnsubscribeRequestSchema, async (request) => {
+  clearInterval(subscriptions.get(request.params.uri));
+  subscriptions.delete(request.params.uri);
+  return {};
+});
+"#,
+        );
+    }
+
+    /// Collects the parameters for a given OpenAPI operation and returns them as a vector of strings
+    /// formatted for use with the Zod schema in TypeScript code generation.
+    ///
+    /// This function inspects the provided OpenAPI operation object and extracts both query parameters
+    /// and request body properties (if present). Query parameters are added as optional strings,
+    /// while request body properties are added as required strings.
+    ///
+    /// # Arguments
+    ///
+    /// * `operation` - A reference to a serde_json::Value representing the OpenAPI operation object.
+    ///
+    /// # Returns
+    ///
+    /// A vector of strings, each representing a parameter definition suitable for use in a Zod schema.
+    fn collect_parameters(&self, operation: &Value) -> Vec<String> {
+        let mut params = Vec::new();
+
+        // Collect query parameters.
+        for name in self.query_parameter_names(operation) {
+            params.push(format!("{}: z.string().optional()", object_key(name)));
+        }
+
+        // Let callers override the response language where the operation supports it.
+        if self.declares_accept_language(operation) {
+            params.push("acceptLanguage: z.string().optional()".to_string());
+        }
+
+        // Collect request body parameters.
+        match self.body_format(operation) {
+            Some(BodyFormat::JsonPatch) => params.push(format!("patch: {}", JSON_PATCH_SCHEMA)),
+            Some(format) => {
+                if let Some(schema) = self.request_body_schema(operation) {
+                    let is_partial = format == BodyFormat::MergePatch;
+                    let names = self.body_parameter_names(operation);
+                    self.process_schema_properties(schema, is_partial, &names, &mut params);
+                }
+            }
+            None => {}
+        }
+
+        params
+    }
+
+    /// Returns the names of the query parameters of an operation.
+    ///
+    /// # Arguments
+    ///
+    /// * `operation` - A reference to a serde_json::Value representing the OpenAPI operation object.
+    ///
+    /// # Returns
+    ///
+    /// The query parameter names, in spec order.
+    fn query_parameter_names<'a>(&self, operation: &'a Value) -> Vec<&'a str> {
+        operation
+            .get("parameters")
+            .and_then(|p| p.as_array())
+            .map(|parameters| {
+                parameters
+                    .iter()
+                    .filter(|param| param.get("in").and_then(|i| i.as_str()) == Some("query"))
+                    .filter_map(|param| param.get("name").and_then(|n| n.as_str()))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Returns the supported request body format of an operation.
+    ///
+    /// # Arguments
+    ///
+    /// * `operation` - A reference to a serde_json::Value representing the OpenAPI operation object.
+    ///
+    /// # Returns
+    ///
+    /// The preferred supported format, or `None` if the operation has no body in a supported format.
+    fn body_format(&self, operation: &Value) -> Option<BodyFormat> {
+        let content = operation.get("requestBody")?.get("content")?;
+        BodyFormat::ALL
+            .into_iter()
+            .find(|format| content.get(format.content_type()).is_some())
+    }
+
+    /// Returns the request body schema of an operation, resolving component references.
+    ///
+    /// # Arguments
+    ///
+    /// * `operation` - A reference to a serde_json::Value representing the OpenAPI operation object.
+    ///
+    /// # Returns
+    ///
+    /// The request body schema, or `None` if the operation has no body in a supported format or
+    /// its reference cannot be resolved.
+    fn request_body_schema<'a>(&'a self, operation: &'a Value) -> Option<&'a Value> {
+        let format = self.body_format(operation)?;
+        let schema = operation
+            .get("requestBody")?
+            .get("content")?
+            .get(format.content_type())?
+            .get("schema")?;
+
+        match schema.get("$ref").and_then(|r| r.as_str()) {
+            Some(ref_path) => self.resolve_reference(ref_path),
+            None => Some(schema),
+        }
+    }
+
+    /// Processes the properties of a schema and adds them to the parameters list.
+    ///
+    /// # Arguments
+    ///
+    /// * `schema` - The schema object containing properties to process.
+    /// * `is_partial` - Whether the body is a merge patch, making every property optional and
+    ///   nullable so fields can be left unchanged or removed.
+    /// * `names` - `(property name, parameter name)` pairs of renamed properties.
+    /// * `params` - A mutable reference to the vector where parameter definitions will be added.
+    fn process_schema_properties(
+        &self,
+        schema: &Value,
+        is_partial: bool,
+        names: &[(String, String)],
+        params: &mut Vec<String>,
+    ) {
+        if let Some(properties) = schema.get("properties")
+            && let Some(props_obj) = properties.as_object()
+        {
+            let required = schema
+                .get("required")
+                .and_then(|r| r.as_array())
+                .map(|arr| arr.iter().filter_map(|v| v.as_str()).collect::<Vec<_>>())
+                .unwrap_or_default();
+
+            for (prop_name, prop_schema) in props_obj {
+                let param_name = names
+                    .iter()
+                    .find(|(property, _)| property == prop_name)
+                    .map_or(prop_name, |(_, param_name)| param_name);
+                let param_name = object_key(param_name);
+                let type_def = self.get_type_definition(prop_schema);
+                let is_required = required.contains(&prop_name.as_str());
+                let param_def = if is_partial {
+                    format!("{}: {}.nullable().optional()", param_name, type_def)
+                } else if is_required {
+                    format!("{}: {}", param_name, type_def)
+                } else {
+                    format!("{}: {}.optional()", param_name, type_def)
+                };
+                params.push(param_def);
+            }
+        }
+    }
+
+    /// Returns the tool parameter names of request body properties that are renamed.
+    ///
+    /// With the camelCase option, properties are exposed in camelCase unless that name is also
+    /// claimed by another property, a query parameter, or `acceptLanguage`. Properties whose
+    /// camelCase name is claimed more than once keep their original name.
+    ///
+    /// # Arguments
+    ///
+    /// * `operation` - A reference to a serde_json::Value representing the OpenAPI operation object.
+    ///
+    /// # Returns
+    ///
+    /// A vector of `(property name, parameter name)` pairs in spec order, empty unless the
+    /// camelCase option is set.
+    fn body_parameter_names(&self, operation: &Value) -> Vec<(String, String)> {
+        let Some(props_obj) = self
+            .request_body_schema(operation)
+            .and_then(|schema| schema.get("properties"))
+            .and_then(|properties| properties.as_object())
+        else {
+            return Vec::new();
+        };
+        if !self.options.camel_case {
+            return Vec::new();
+        }
+
+        // Count every claim on a name: query parameters, `acceptLanguage`, and body properties
+        // under their camelCase name, plus body properties whose name is already camelCase.
+        let mut claims = HashMap::<String, usize>::new();
+        for name in self.query_parameter_names(operation) {
+            *claims.entry(name.to_string()).or_default() += 1;
+        }
+        if self.declares_accept_language(operation) {
+            *claims.entry("acceptLanguage".to_string()).or_default() += 1;
+        }
+        for property in props_obj.keys() {
+            *claims.entry(to_camel_case(property)).or_default() += 1;
+            if to_camel_case(property) != *property {
+                *claims.entry(property.clone()).or_default() += 1;
+            }
+        }
+
+        props_obj
+            .keys()
+            .filter_map(|property| {
+                let camel_case = to_camel_case(property);
+                (camel_case != *property && claims[&camel_case] == 1)
+                    .then(|| (property.clone(), camel_case))
+            })
+            .collect()
+    }
+
+    /// Determines the Zod type definition for a given schema.
+    ///
+    /// # Arguments
+    ///
+    /// * `schema` - The schema object to determine the type for.
+    ///
+    /// # Returns
+    ///
+    /// A string representing the Zod type definition.
+    fn get_type_definition(&self, schema: &Value) -> String {
+        match schema.get("type").and_then(|t| t.as_str()) {
+            Some("string") => "z.string()".to_string(),
+            Some("number") => "z.number()".to_string(),
+            Some("integer") => "z.number().int()".to_string(),
+            Some("boolean") => "z.boolean()".to_string(),
+            Some("array") => {
+                if let Some(items) = schema.get("items") {
                     if let Some(item_type) = items.get("type").and_then(|t| t.as_str()) {
                         match item_type {
                             "string" => "z.array(z.string())".to_string(),
@@ -299,19 +1485,296 @@ const server = new McpServer({
         }
     }
 
-    /// Appends the TypeScript code required to establish a server connection using
-    /// the StdioServerTransport and connect it to the generated server.
+    /// Appends the TypeScript code required to establish a server connection using the configured
+    /// transport and connect it to the generated server.
+    ///
+    /// The HTTP transport serves MCP on `/mcp` with a server and stateless transport per `POST`
+    /// request, answering other methods with `405`, and a health check on `/healthz`, listening on the port from the `PORT` environment variable.
+    ///
+    /// # Arguments
+    ///
+    /// * `code` - A mutable reference to the String where the generated TypeScript code is appended.
+    fn add_server_connection(&self, code: &mut String) {
+        if self.options.runtime.serves_per_request() {
+            self.add_web_handler(code);
+            match self.options.runtime {
+                Runtime::CloudflareWorkers => {
+                    code.push_str("\nexport default {\n  fetch: handleMcpRequest,\n};\n")
+                }
+                Runtime::AwsLambda => self.add_lambda_handler(code),
+                Runtime::Node => {}
+            }
+            return;
+        }
+
+        match self.options.transport() {
+            Transport::Stdio => code.push_str(
+                "\nconst transport = new StdioServerTransport();\nawait server.connect(transport);\n",
+            ),
+            Transport::Http => code.push_str(
+                r#"
+const port = Number(env.PORT ?? 8080);
+http
+  .createServer(async (req, res) => {
+    const { pathname } = new URL(req.url ?? "/", "http://localhost");
+    if (pathname === "/healthz") {
+      res.writeHead(200).end("ok");
+    } else if (pathname === "/mcp" && req.method !== "POST") {
+      // Without sessions there is no stream to resume or session to end, so only POST is served.
+      res.writeHead(405, { Allow: "POST" }).end();
+    } else if (pathname === "/mcp") {
+      // A stateless transport cannot be shared between requests, so each gets a server of its own.
+      const server = createServer();
+      const transport = new StreamableHTTPServerTransport({ sessionIdGenerator: undefined });
+      res.on("close", () => {
+        transport.close();
+        server.close();
+      });
+      await server.connect(transport);
+      await transport.handleRequest(req, res);
+    } else {
+      res.writeHead(404).end();
+    }
+  })
+  .listen(port, () => {
+    console.error(`MCP server listening on port ${port}`);
+  });
+"#,
+            ),
+        }
+    }
+
+    /// Appends a Streamable HTTP request handler built on web-standard APIs only.
+    ///
+    /// Each request gets a server of its own, connected to a transport that collects the
+    /// responses to the request's JSON-RPC messages and answers with a single JSON response.
+    /// The handler serves MCP on `/mcp` and a health check on `/healthz`.
+    ///
+    /// # Arguments
+    ///
+    /// * `code` - A mutable reference to the String where the generated TypeScript code is appended.
+    fn add_web_handler(&self, code: &mut String) {
+        code.push_str(
+            r#"
+/**
+ * Answers a Streamable HTTP request with a JSON response.
+ */
+async function handleMcpRequest(request: Request): Promise<Response> {
+  const { pathname } = new URL(request.url);
+  if (pathname === "/healthz") {
+    return new Response("ok");
+  }
+  if (pathname !== "/mcp") {
+    return new Response(null, { status: 404 });
+  }
+  if (request.method !== "POST") {
+    return new Response(null, { status: 405, headers: { Allow: "POST" } });
+  }
+
+  let body: unknown;
+  try {
+    body = await request.json();
+  } catch {
+    return Response.json(
+      { jsonrpc: "2.0", id: null, error: { code: -32700, message: "Parse error" } },
+      { status: 400 }
+    );
+  }
+
+  const messages = (Array.isArray(body) ? body : [body]) as JSONRPCMessage[];
+  const pending = new Set(messages.filter(isJSONRPCRequest).map((message) => message.id));
+  const responses: JSONRPCMessage[] = [];
+  let finish = () => {};
+  const finished = new Promise<void>((resolve) => {
+    finish = resolve;
+  });
+
+  const transport: Transport = {
+    async start() {},
+    async close() {},
+    async send(message) {
+      if ((isJSONRPCResponse(message) || isJSONRPCError(message)) && pending.delete(message.id)) {
+        responses.push(message);
+        if (pending.size === 0) finish();
+      }
+    },
+  };
+
+  const server = createServer();
+  await server.connect(transport);
+  for (const message of messages) {
+    transport.onmessage?.(message);
+  }
+  if (pending.size > 0) {
+    await finished;
+  }
+  await server.close();
+
+  if (responses.length === 0) {
+    return new Response(null, { status: 202 });
+  }
+  return Response.json(Array.isArray(body) ? responses : responses[0]);
+}
+"#,
+        );
+    }
+
+    /// Appends the AWS Lambda handler, which adapts API Gateway HTTP API and Function URL events
+    /// (payload format 2.0) to the web-standard request handler.
     ///
     /// # Arguments
     ///
     /// * `code` - A mutable reference to the String where the generated TypeScript code is appended.
-    fn add_server_connection(&self, code: &mut String) {
+    fn add_lambda_handler(&self, code: &mut String) {
         code.push_str(
-            "\nconst transport = new StdioServerTransport();\nawait server.connect(transport);\n",
+            r#"
+/**
+ * Handles API Gateway HTTP API and Lambda Function URL events.
+ */
+export async function handler(
+  event: APIGatewayProxyEventV2
+): Promise<APIGatewayProxyStructuredResultV2> {
+  const { domainName, http } = event.requestContext;
+  const query = event.rawQueryString ? `?${event.rawQueryString}` : "";
+  const headers = new Headers();
+  for (const [name, value] of Object.entries(event.headers ?? {})) {
+    if (value !== undefined) headers.set(name, value);
+  }
+  const body =
+    event.body && event.isBase64Encoded
+      ? Buffer.from(event.body, "base64").toString("utf8")
+      : event.body;
+
+  const response = await handleMcpRequest(
+    new Request(`https://${domainName}${event.rawPath}${query}`, {
+      method: http.method,
+      headers,
+      body: http.method === "GET" || http.method === "HEAD" ? undefined : body,
+    })
+  );
+
+  return {
+    statusCode: response.status,
+    headers: Object.fromEntries(response.headers),
+    body: await response.text(),
+  };
+}
+"#,
         );
     }
 }
 
+/// Builds the JSON pointer to an operation in the specification.
+///
+/// # Arguments
+///
+/// * `path` - The HTTP path for the operation (e.g., "/users/{id}").
+/// * `method` - The HTTP method for the operation (e.g., "get").
+///
+/// # Returns
+///
+/// The pointer, such as `#/paths/~1users~1{id}/get`.
+fn operation_pointer(path: &str, method: &str) -> String {
+    format!(
+        "#/paths/{}/{}",
+        path.replace('~', "~0").replace('/', "~1"),
+        method
+    )
+}
+
+/// Describes an operation for reports, such as `GET /users (listUsers)`.
+///
+/// # Arguments
+///
+/// * `path` - The HTTP path for the operation.
+/// * `method` - The HTTP method for the operation.
+/// * `operation_id` - The tool name of the operation.
+///
+/// # Returns
+///
+/// The method, path, and tool name of the operation.
+fn operation_label(path: &str, method: &str, operation_id: &str) -> String {
+    format!("{} {} ({})", method.to_uppercase(), path, operation_id)
+}
+
+/// Indents every non-empty line of a block of code.
+///
+/// # Arguments
+///
+/// * `code` - The code to indent.
+/// * `prefix` - The indentation to prepend to each line.
+///
+/// # Returns
+///
+/// The indented code, keeping its trailing newline.
+fn indent(code: &str, prefix: &str) -> String {
+    code.lines()
+        .map(|line| {
+            if line.is_empty() {
+                "\n".to_string()
+            } else {
+                format!("{}{}\n", prefix, line)
+            }
+        })
+        .collect()
+}
+
+/// Returns a name as a TypeScript object key, quoting it unless it is a valid identifier.
+///
+/// # Arguments
+///
+/// * `name` - The key name.
+///
+/// # Returns
+///
+/// The name, or a quoted string literal if it contains characters an identifier cannot.
+fn object_key(name: &str) -> String {
+    let is_identifier = name
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_' || c == '$')
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$');
+    if is_identifier {
+        name.to_string()
+    } else {
+        Value::from(name).to_string()
+    }
+}
+
+/// Converts a snake_case, kebab-case, or space separated name to camelCase.
+///
+/// # Arguments
+///
+/// * `name` - The name to convert.
+///
+/// # Returns
+///
+/// The camelCase name, or the original name if it has no separators.
+fn to_camel_case(name: &str) -> String {
+    let segments = name
+        .split(['_', '-', ' '])
+        .filter(|segment| !segment.is_empty())
+        .collect::<Vec<_>>();
+    let [first, rest @ ..] = segments.as_slice() else {
+        return name.to_string();
+    };
+    if rest.is_empty() {
+        return first.to_string();
+    }
+
+    let mut camel_case = first.to_lowercase();
+    for segment in rest {
+        let mut chars = segment.chars();
+        if let Some(initial) = chars.next() {
+            camel_case.extend(initial.to_uppercase());
+            camel_case.push_str(chars.as_str());
+        }
+    }
+    camel_case
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -359,7 +1822,7 @@ mod tests {
     #[test]
     fn test_collect_parameters() {
         let openapi = create_test_openapi();
-        let generator = CodeGenerator::new(openapi.clone());
+        let generator = CodeGenerator::new(openapi.clone(), GeneratorOptions::default());
         let operation = openapi["paths"]["/test"]["get"].clone();
 
         let params = generator.collect_parameters(&operation);
@@ -372,7 +1835,7 @@ mod tests {
     #[test]
     fn test_generate_tool() {
         let openapi = create_test_openapi();
-        let generator = CodeGenerator::new(openapi.clone());
+        let generator = CodeGenerator::new(openapi.clone(), GeneratorOptions::default());
         let operation = openapi["paths"]["/test"]["get"].clone();
 
         let mut code = String::new();
@@ -380,13 +1843,42 @@ mod tests {
 
         assert!(code.contains("testOperation"));
         assert!(code.contains("const search = new URLSearchParams()"));
+        assert!(code.contains("for (const key of [\"queryParam\"]) {"));
         assert!(code.contains("method: \"GET\""));
     }
 
+    #[test]
+    fn test_generate_tool_with_query_named_body_property() {
+        let operation = json!({
+            "operationId": "searchUsers",
+            "requestBody": {
+                "content": {
+                    "application/json": {
+                        "schema": {
+                            "type": "object",
+                            "properties": { "query_text": { "type": "string" } }
+                        }
+                    }
+                }
+            }
+        });
+        let options = GeneratorOptions {
+            camel_case: true,
+            ..GeneratorOptions::default()
+        };
+        let generator = CodeGenerator::new(json!({}), options);
+
+        let mut code = String::new();
+        generator.generate_tool(&mut code, "/users/search", "post", &operation);
+
+        assert!(!code.contains("URLSearchParams"));
+        assert!(code.contains("\"/users/search\","));
+    }
+
     #[test]
     fn test_add_imports() {
         let openapi = create_test_openapi();
-        let generator = CodeGenerator::new(openapi);
+        let generator = CodeGenerator::new(openapi, GeneratorOptions::default());
 
         let mut code = String::new();
         generator.add_imports(&mut code);
@@ -398,10 +1890,523 @@ mod tests {
         assert!(code.contains("const server = new McpServer"));
     }
 
+    #[test]
+    fn test_generate_tool_with_description() {
+        let operation = json!({
+            "operationId": "listUsers",
+            "summary": "List users",
+            "description": "Returns every user."
+        });
+        let generator = CodeGenerator::new(json!({}), GeneratorOptions::default());
+
+        let mut code = String::new();
+        generator.generate_tool(&mut code, "/users", "get", &operation);
+
+        assert!(code.contains("  \"listUsers\",\n  \"List users\\n\\nReturns every user.\",\n  {"));
+        assert!(!code.contains("api-docs://"));
+    }
+
+    #[test]
+    fn test_generate_tool_with_truncated_description() {
+        let operation = json!({
+            "operationId": "listUsers",
+            "description": "Returns every user in the organization."
+        });
+        let options = GeneratorOptions {
+            max_description_length: Some(21),
+            ..GeneratorOptions::default()
+        };
+        let generator = CodeGenerator::new(json!({}), options);
+
+        let mut code = String::new();
+        generator.generate_tool(&mut code, "/users", "get", &operation);
+
+        assert!(code.contains(
+            "\"Returns every user in… (full docs available via resource api-docs://listUsers)\""
+        ));
+        assert!(code.contains("\"api-docs://listUsers\""));
+        assert!(code.contains("text: \"Returns every user in the organization.\""));
+    }
+
+    #[test]
+    fn test_generate_tool_checks_config() {
+        let openapi = create_test_openapi();
+        let generator = CodeGenerator::new(openapi.clone(), GeneratorOptions::default());
+        let operation = openapi["paths"]["/test"]["get"].clone();
+
+        let mut code = String::new();
+        generator.generate_tool(&mut code, "/test", "get", &operation);
+
+        let guard = code
+            .find("if (missingConfig.length > 0) return configErrorResult();")
+            .unwrap();
+        assert!(guard < code.find("callBackend").unwrap());
+    }
+
+    #[test]
+    fn test_add_config_check() {
+        let openapi = create_test_openapi();
+        let generator = CodeGenerator::new(openapi, GeneratorOptions::default());
+
+        let mut code = String::new();
+        generator.add_config_check(&mut code);
+        generator.add_config_status_tool(&mut code);
+
+        assert!(code.contains("const REQUIRED_CONFIG = [\"BACKEND_URL\"];"));
+        assert!(code.contains("function configErrorResult()"));
+        assert!(code.contains("\"__config_status\""));
+    }
+
+    #[test]
+    fn test_generate_tool_with_accept_language() {
+        let operation = json!({
+            "operationId": "getArticle",
+            "parameters": [
+                { "name": "Accept-Language", "in": "header", "schema": { "type": "string" } }
+            ]
+        });
+        let generator = CodeGenerator::new(json!({}), GeneratorOptions::default());
+
+        let params = generator.collect_parameters(&operation);
+        let mut code = String::new();
+        generator.generate_tool(&mut code, "/articles", "get", &operation);
+
+        assert_eq!(params, vec!["acceptLanguage: z.string().optional()"]);
+        assert!(code.contains("async ({ acceptLanguage, ...params }) =>"));
+        assert!(code.contains(
+            "headers: { ...(acceptLanguage ? { \"Accept-Language\": acceptLanguage } : {}) }"
+        ));
+    }
+
+    #[test]
+    fn test_generate_reports_all_issues() {
+        let openapi = json!({
+            "paths": {
+                "/users": {
+                    "post": {
+                        "operationId": "createUser",
+                        "requestBody": {
+                            "content": {
+                                "application/json": {
+                                    "schema": { "$ref": "#/components/schemas/Missing" }
+                                }
+                            }
+                        }
+                    },
+                    "put": {
+                        "operationId": "uploadUsers",
+                        "requestBody": {
+                            "content": { "text/csv": { "schema": { "type": "string" } } }
+                        }
+                    },
+                    "get": { "operationId": "listUsers" }
+                },
+                "/people": {
+                    "get": { "operationId": "listUsers" }
+                }
+            }
+        });
+        let generator = CodeGenerator::new(openapi, GeneratorOptions::default());
+
+        let Err(OpenApiToMcpError::Generation(report)) = generator.generate() else {
+            panic!("expected a generation report");
+        };
+        let messages = report.to_string();
+
+        assert_eq!(report.total, 4);
+        assert_eq!(report.issues.len(), 2);
+        assert!(messages.starts_with("Failed to generate 2 of 4 operations (2 issues):"));
+        assert!(messages.contains(
+            "POST /users (createUser) at #/paths/~1users/post: unresolved reference `#/components/schemas/Missing`"
+        ));
+        assert!(!messages.contains("uploadUsers"));
+        assert!(messages.contains("duplicate tool name `listUsers`"));
+
+        let warnings = generator.unsupported_bodies();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].operation, "PUT /users (uploadUsers)");
+        assert_eq!(
+            warnings[0].message,
+            "unsupported request body content type `text/csv`"
+        );
+    }
+
+    #[test]
+    fn test_generate_with_unsupported_request_body() {
+        let openapi = json!({
+            "paths": {
+                "/avatars": {
+                    "post": {
+                        "operationId": "uploadAvatar",
+                        "requestBody": {
+                            "content": { "multipart/form-data": { "schema": { "type": "object" } } }
+                        }
+                    },
+                    "get": { "operationId": "listAvatars" }
+                }
+            }
+        });
+        let generator = CodeGenerator::new(openapi, GeneratorOptions::default());
+
+        let code = generator.generate().unwrap();
+
+        assert!(code.contains("\nserver.tool(\n  \"uploadAvatar\","));
+        assert!(code.contains("\nserver.tool(\n  \"listAvatars\","));
+        assert!(code.contains("{\n        method: \"POST\"\n      }"));
+        assert!(!code.contains("Content-Type"));
+        assert!(!code.contains("body: JSON.stringify"));
+        assert_eq!(generator.unsupported_bodies().len(), 1);
+    }
+
+    #[test]
+    fn test_generate_privileged_tool() {
+        let openapi = json!({
+            "paths": {
+                "/users/{id}": {
+                    "delete": { "operationId": "deleteUser", "x-mcp-privileged": true }
+                },
+                "/users": {
+                    "get": { "operationId": "listUsers" }
+                }
+            }
+        });
+        let generator = CodeGenerator::new(openapi, GeneratorOptions::default());
+
+        let mut code = String::new();
+        let mut issues = Vec::new();
+        generator.generate_tools(&mut code, &mut issues);
+
+        assert!(
+            code.contains("if (privilegedToolsEnabled) {\n  server.tool(\n    \"deleteUser\",")
+        );
+        assert!(code.contains("\nserver.tool(\n  \"listUsers\","));
+    }
+
+    #[test]
+    fn test_generate_tools_with_max_tools() {
+        let openapi = json!({
+            "paths": {
+                "/health": {
+                    "options": { "operationId": "healthOptions" }
+                },
+                "/users": {
+                    "get": { "operationId": "listUsers", "deprecated": true },
+                    "post": {
+                        "operationId": "createUser",
+                        "responses": {
+                            "201": {
+                                "links": {
+                                    "GetUser": { "operationRef": "#/paths/~1users~1%7Bid%7D/get" }
+                                }
+                            }
+                        }
+                    }
+                },
+                "/users/{id}": {
+                    "get": { "operationId": "getUser" }
+                },
+                "/reports": {
+                    "get": { "operationId": "getReport", "tags": ["Core"] }
+                }
+            }
+        });
+        let options = GeneratorOptions {
+            max_tools: Some(3),
+            ..GeneratorOptions::default()
+        };
+        let generator = CodeGenerator::new(openapi, options);
+
+        let pruned = generator.pruned_tools();
+        let mut code = String::new();
+        let mut issues = Vec::new();
+        let total = generator.generate_tools(&mut code, &mut issues);
+
+        assert_eq!(
+            pruned
+                .iter()
+                .map(|tool| (tool.operation.as_str(), tool.score))
+                .collect::<Vec<_>>(),
+            vec![
+                ("OPTIONS /health (healthOptions)", 0),
+                ("GET /users (listUsers)", -1)
+            ]
+        );
+        assert_eq!(pruned[0].pointer, "#/paths/~1health/options");
+        assert_eq!(total, 3);
+        assert!(code.contains("\"getReport\""));
+        assert!(code.contains("\"getUser\""));
+        assert!(code.contains("\"createUser\""));
+        assert!(!code.contains("\"listUsers\""));
+    }
+
+    #[test]
+    fn test_pruned_tools_keeps_spec_order_between_ties() {
+        let openapi = json!({
+            "paths": {
+                "/zeta": { "get": { "operationId": "getZeta" } },
+                "/alpha": { "get": { "operationId": "getAlpha" } }
+            }
+        });
+        let options = GeneratorOptions {
+            max_tools: Some(1),
+            ..GeneratorOptions::default()
+        };
+        let generator = CodeGenerator::new(openapi, options);
+
+        let pruned = generator.pruned_tools();
+
+        assert_eq!(pruned.len(), 1);
+        assert_eq!(pruned[0].operation, "GET /alpha (getAlpha)");
+    }
+
+    #[test]
+    fn test_generate_tool_with_json_patch_body() {
+        let operation = json!({
+            "operationId": "patchUser",
+            "requestBody": {
+                "content": {
+                    "application/json-patch+json": {
+                        "schema": { "type": "array", "items": { "type": "object" } }
+                    }
+                }
+            }
+        });
+        let generator = CodeGenerator::new(json!({}), GeneratorOptions::default());
+
+        let params = generator.collect_parameters(&operation);
+        let mut code = String::new();
+        generator.generate_tool(&mut code, "/users", "patch", &operation);
+
+        assert_eq!(params, vec![format!("patch: {}", JSON_PATCH_SCHEMA)]);
+        assert!(code.contains("headers: { \"Content-Type\": \"application/json-patch+json\" }"));
+        assert!(code.contains("body: JSON.stringify(params.patch)"));
+    }
+
+    #[test]
+    fn test_generate_tool_with_merge_patch_body() {
+        let operation = json!({
+            "operationId": "patchUser",
+            "requestBody": {
+                "content": {
+                    "application/merge-patch+json": {
+                        "schema": {
+                            "type": "object",
+                            "properties": { "name": { "type": "string" } },
+                            "required": ["name"]
+                        }
+                    }
+                }
+            }
+        });
+        let generator = CodeGenerator::new(json!({}), GeneratorOptions::default());
+
+        let params = generator.collect_parameters(&operation);
+        let mut code = String::new();
+        generator.generate_tool(&mut code, "/users", "patch", &operation);
+
+        assert_eq!(params, vec!["name: z.string().nullable().optional()"]);
+        assert!(code.contains("headers: { \"Content-Type\": \"application/merge-patch+json\" }"));
+        assert!(code.contains("body: JSON.stringify(params)"));
+    }
+
+    #[test]
+    fn test_generate_tool_with_debug_curl() {
+        let openapi = create_test_openapi();
+        let options = GeneratorOptions {
+            debug_curl: true,
+            ..GeneratorOptions::default()
+        };
+        let generator = CodeGenerator::new(openapi.clone(), options);
+        let operation = openapi["paths"]["/test"]["get"].clone();
+
+        let mut imports = String::new();
+        generator.add_imports(&mut imports);
+        let mut code = String::new();
+        generator.generate_tool(&mut code, "/test", "get", &operation);
+
+        assert!(imports.contains("function toCurl("));
+        assert!(imports.contains("options?: RequestInit, trace?: string[]"));
+        assert!(code.contains("    const curl: string[] = [];\n    try {\n"));
+        assert!(code.contains("        curl\n      );"));
+        assert!(code.contains("        _meta: { curl },\n      };\n    } catch (error) {"));
+    }
+
+    #[test]
+    fn test_add_imports_redacts_curl_secrets() {
+        let options = GeneratorOptions {
+            debug_curl: true,
+            ..GeneratorOptions::default()
+        };
+        let generator = CodeGenerator::new(create_test_openapi(), options);
+
+        let mut imports = String::new();
+        generator.add_imports(&mut imports);
+
+        assert!(
+            imports.contains("[key, SENSITIVE_NAME.test(key) ? \"REDACTED\" : redactJson(item)]")
+        );
+        assert!(imports.contains("redactedBody = JSON.stringify(redactJson(JSON.parse(body)));"));
+        assert!(imports.contains("parsed.username = \"\";\n    parsed.password = \"\";"));
+    }
+
+    #[test]
+    fn test_to_camel_case() {
+        assert_eq!(to_camel_case("first_name"), "firstName");
+        assert_eq!(to_camel_case("created-at"), "createdAt");
+        assert_eq!(to_camel_case("alreadyCamel"), "alreadyCamel");
+        assert_eq!(to_camel_case("__"), "__");
+        assert_eq!(to_camel_case("First_Name"), "firstName");
+    }
+
+    #[test]
+    fn test_generate_tool_with_camel_case() {
+        let operation = json!({
+            "operationId": "createUser",
+            "requestBody": {
+                "content": {
+                    "application/json": {
+                        "schema": {
+                            "type": "object",
+                            "properties": {
+                                "first_name": { "type": "string" },
+                                "last_name": { "type": "string" },
+                                "lastName": { "type": "string" },
+                                "age": { "type": "integer" }
+                            },
+                            "required": ["first_name"]
+                        }
+                    }
+                }
+            }
+        });
+        let options = GeneratorOptions {
+            camel_case: true,
+            ..GeneratorOptions::default()
+        };
+        let generator = CodeGenerator::new(json!({}), options);
+
+        let params = generator.collect_parameters(&operation);
+        let mut code = String::new();
+        generator.generate_tool(&mut code, "/users", "post", &operation);
+
+        assert!(params.contains(&"firstName: z.string()".to_string()));
+        assert!(params.contains(&"last_name: z.string().optional()".to_string()));
+        assert!(params.contains(&"lastName: z.string().optional()".to_string()));
+        assert!(
+            code.contains(
+                "body: JSON.stringify(renameKeys(params, { firstName: \"first_name\" }))"
+            )
+        );
+    }
+
+    #[test]
+    fn test_generate_tool_with_camel_case_collisions() {
+        let operation = json!({
+            "operationId": "createUser",
+            "parameters": [
+                { "name": "firstName", "in": "query" }
+            ],
+            "requestBody": {
+                "content": {
+                    "application/json": {
+                        "schema": {
+                            "type": "object",
+                            "properties": {
+                                "first_name": { "type": "string" },
+                                "first-name": { "type": "string" }
+                            }
+                        }
+                    }
+                }
+            }
+        });
+        let options = GeneratorOptions {
+            camel_case: true,
+            ..GeneratorOptions::default()
+        };
+        let generator = CodeGenerator::new(json!({}), options);
+
+        let params = generator.collect_parameters(&operation);
+        let mut code = String::new();
+        generator.generate_tool(&mut code, "/users", "post", &operation);
+
+        assert_eq!(
+            params,
+            vec![
+                "firstName: z.string().optional()",
+                "first_name: z.string().optional()",
+                "\"first-name\": z.string().optional()",
+            ]
+        );
+        assert!(code.contains("body: JSON.stringify(params)"));
+        assert!(!code.contains("renameKeys("));
+    }
+
+    #[test]
+    fn test_is_watchable() {
+        let generator = CodeGenerator::new(json!({}), GeneratorOptions::default());
+
+        assert!(generator.is_watchable("/events", "get", &json!({})));
+        assert!(generator.is_watchable("/feed", "get", &json!({"tags": ["Notifications"]})));
+        assert!(generator.is_watchable("/feed", "get", &json!({"x-mcp-watch": true})));
+        assert!(!generator.is_watchable("/events", "get", &json!({"x-mcp-watch": false})));
+        assert!(!generator.is_watchable("/events", "post", &json!({})));
+        assert!(!generator.is_watchable("/events/{id}", "get", &json!({})));
+        assert!(!generator.is_watchable("/users", "get", &json!({})));
+    }
+
+    #[test]
+    fn test_generate_watched_resources() {
+        let openapi = json!({
+            "paths": {
+                "/events": {
+                    "get": { "operationId": "listEvents" }
+                }
+            }
+        });
+        let options = GeneratorOptions {
+            watch_resources: true,
+            poll_interval: Some(5),
+            ..GeneratorOptions::default()
+        };
+        let generator = CodeGenerator::new(openapi, options);
+
+        let code = generator.generate().unwrap();
+
+        assert!(code.contains("SubscribeRequestSchema,"));
+        assert!(code.contains("\"events://listEvents\": \"/events\","));
+        assert!(code.contains("const POLL_INTERVAL_MS = 5000;"));
+        assert!(code.contains("resources: { subscribe: true }"));
+        assert!(code.contains("server.server.sendResourceUpdated({ uri })"));
+    }
+
+    #[test]
+    fn test_generate_privileged_watched_resource() {
+        let openapi = json!({
+            "paths": {
+                "/admin/events": {
+                    "get": { "operationId": "listAdminEvents", "x-mcp-privileged": true }
+                }
+            }
+        });
+        let options = GeneratorOptions {
+            watch_resources: true,
+            ..GeneratorOptions::default()
+        };
+        let generator = CodeGenerator::new(openapi, options);
+
+        let code = generator.generate().unwrap();
+
+        assert!(code.contains("const watchedResources: Record<string, string> = {\n\n};"));
+        assert!(code.contains(
+            "if (privilegedToolsEnabled) {\n  watchedResources[\"events://listAdminEvents\"] = \"/admin/events\";\n\n  server.resource(\n    \"listAdminEvents\","
+        ));
+    }
+
     #[test]
     fn test_add_server_connection() {
         let openapi = create_test_openapi();
-        let generator = CodeGenerator::new(openapi);
+        let generator = CodeGenerator::new(openapi, GeneratorOptions::default());
 
         let mut code = String::new();
         generator.add_server_connection(&mut code);
@@ -410,12 +2415,83 @@ mod tests {
         assert!(code.contains("await server.connect(transport)"));
     }
 
+    #[test]
+    fn test_add_server_connection_over_http() {
+        let options = GeneratorOptions {
+            transport: Some(Transport::Http),
+            ..GeneratorOptions::default()
+        };
+        let generator = CodeGenerator::new(create_test_openapi(), options);
+
+        let code = generator.generate().unwrap();
+
+        assert!(code.contains("import { StreamableHTTPServerTransport }"));
+        assert!(!code.contains("StdioServerTransport"));
+        assert!(
+            code.contains("new StreamableHTTPServerTransport({ sessionIdGenerator: undefined })")
+        );
+        assert!(code.contains("await transport.handleRequest(req, res);"));
+        assert!(
+            code.contains("function createServer(): McpServer {\n  const server = new McpServer({")
+        );
+        assert!(!code.contains("\nconst server = new McpServer({"));
+        assert!(code.contains(
+            "      const server = createServer();\n      const transport = new StreamableHTTPServerTransport"
+        ));
+        assert!(code.contains(
+            "res.on(\"close\", () => {\n        transport.close();\n        server.close();"
+        ));
+        assert!(code.contains("res.writeHead(405, { Allow: \"POST\" }).end();"));
+    }
+
+    #[test]
+    fn test_generate_for_cloudflare_workers() {
+        let options = GeneratorOptions {
+            runtime: Runtime::CloudflareWorkers,
+            ..GeneratorOptions::default()
+        };
+        let generator = CodeGenerator::new(create_test_openapi(), options);
+
+        let code = generator.generate().unwrap();
+
+        assert!(code.contains("import { env as bindings } from \"cloudflare:workers\";"));
+        assert!(!code.contains("dotenv"));
+        assert!(!code.contains("process.env"));
+        assert!(
+            code.contains("function createServer(): McpServer {\n  const server = new McpServer({")
+        );
+        assert!(code.contains("\n  server.tool(\n    \"testOperation\","));
+        assert!(code.contains("  return server;\n}\n"));
+        assert!(code.contains("async function handleMcpRequest(request: Request)"));
+        assert!(code.contains("export default {\n  fetch: handleMcpRequest,\n};"));
+    }
+
+    #[test]
+    fn test_generate_for_aws_lambda() {
+        let options = GeneratorOptions {
+            runtime: Runtime::AwsLambda,
+            ..GeneratorOptions::default()
+        };
+        let generator = CodeGenerator::new(create_test_openapi(), options);
+
+        let code = generator.generate().unwrap();
+
+        assert!(!code.contains("dotenv"));
+        assert!(code.contains("const env: Record<string, string | undefined> = process.env;"));
+        assert!(code.contains("function createServer(): McpServer {"));
+        assert!(code.contains("async function handleMcpRequest(request: Request)"));
+        assert!(
+            code.contains("export async function handler(\n  event: APIGatewayProxyEventV2\n)")
+        );
+        assert!(!code.contains("export default"));
+    }
+
     #[test]
     fn test_generate() {
         let openapi = create_test_openapi();
-        let generator = CodeGenerator::new(openapi);
+        let generator = CodeGenerator::new(openapi, GeneratorOptions::default());
 
-        let code = generator.generate();
+        let code = generator.generate().unwrap();
 
         assert!(code.contains("import { McpServer }"));
         assert!(code.contains("testOperation"));
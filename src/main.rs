@@ -1,15 +1,23 @@
 mod cli;
+mod deployment;
 mod error;
 mod generator;
+mod options;
+mod workspace;
 
 use clap::Parser;
 use serde_json::Value;
 use std::fs;
 use std::path::Path;
+use std::process::ExitCode;
+use std::thread;
 
-use crate::cli::Args;
+use crate::cli::{Args, Command, GenerateArgs};
+use crate::deployment::deployment_files;
 use crate::error::{OpenApiToMcpError, Result};
 use crate::generator::CodeGenerator;
+use crate::options::GeneratorOptions;
+use crate::workspace::Workspace;
 
 /// Recursively copies all files and subdirectories from the source directory to the destination directory.
 ///
@@ -52,38 +60,51 @@ fn copy_dir_all(source: &Path, destination: &Path) -> Result<()> {
 ///
 /// * `openapi_file` - Path to the OpenAPI specification file.
 /// * `output_dir` - Directory where the generated code will be written.
+/// * `options` - Options controlling the generated code.
+/// * `force` - Whether to overwrite generated files in an existing output directory.
 ///
 /// # Returns
 ///
 /// * `Result<()>` - Returns `Ok(())` if the generation succeeds, or an error if any step fails.
-fn generate_mcp_server(openapi_file: &Path, output_dir: &Path) -> Result<()> {
+fn generate_mcp_server(
+    openapi_file: &Path,
+    output_dir: &Path,
+    options: &GeneratorOptions,
+    force: bool,
+) -> Result<()> {
+    options.validate()?;
+
     // Check if the output directory already exists.
-    if output_dir.exists() {
+    if output_dir.exists() && !force {
         return Err(OpenApiToMcpError::OutputDirectoryExists(
             output_dir.to_path_buf(),
         ));
     }
 
-    // Create the output directory.
-    fs::create_dir_all(output_dir)
-        .map_err(|_| OpenApiToMcpError::OutputDirectoryCreation(output_dir.to_path_buf()))?;
-
-    // Copy the templates directory to the output directory.
-    let templates_directory = Path::new("templates");
-    if !templates_directory.exists() {
-        return Err(OpenApiToMcpError::TemplatesDirectoryNotFound);
-    }
-    copy_dir_all(templates_directory, output_dir).map_err(|_| OpenApiToMcpError::TemplatesCopy)?;
-
     // Read and parse the OpenAPI specification.
     let content = fs::read_to_string(openapi_file)
         .map_err(|_| OpenApiToMcpError::OpenApiFileRead(openapi_file.to_path_buf()))?;
     let openapi: Value =
         serde_json::from_str(&content).map_err(|_| OpenApiToMcpError::OpenApiParse)?;
 
-    // Generate TypeScript code.
-    let generator = CodeGenerator::new(openapi);
-    let typescript = generator.generate();
+    // Generate TypeScript code before writing anything, so a failing spec leaves no partial output.
+    let generator = CodeGenerator::new(openapi, options.clone());
+    let typescript = generator.generate()?;
+    let pruned = generator.pruned_tools();
+    let unsupported = generator.unsupported_bodies();
+
+    // Check the templates before creating the output directory, so a missing one leaves no output.
+    let templates_directory = options.templates_directory();
+    if !templates_directory.exists() {
+        return Err(OpenApiToMcpError::TemplatesDirectoryNotFound);
+    }
+
+    // Create the output directory.
+    fs::create_dir_all(output_dir)
+        .map_err(|_| OpenApiToMcpError::OutputDirectoryCreation(output_dir.to_path_buf()))?;
+
+    // Copy the templates directory to the output directory.
+    copy_dir_all(templates_directory, output_dir).map_err(|_| OpenApiToMcpError::TemplatesCopy)?;
 
     // Write index.ts to the output/src directory.
     let output_source = output_dir.join("src");
@@ -91,15 +112,147 @@ fn generate_mcp_server(openapi_file: &Path, output_dir: &Path) -> Result<()> {
     fs::write(output_source.join("index.ts"), typescript)
         .map_err(|_| OpenApiToMcpError::IndexFileWrite)?;
 
+    // Write the deployment files for the requested targets.
+    for file in deployment_files(output_dir, options) {
+        let path = output_dir.join(&file.path);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|_| OpenApiToMcpError::DeploymentFileWrite(path.clone()))?;
+        }
+        fs::write(&path, file.contents)
+            .map_err(|_| OpenApiToMcpError::DeploymentFileWrite(path))?;
+    }
+
     println!(
         "Successfully generated TypeScript code in: {}",
         output_dir.display()
     );
 
+    // Report pruned operations in a single write, so parallel workspace output stays readable.
+    if let Some(max_tools) = options.max_tools
+        && !pruned.is_empty()
+    {
+        let mut report = format!(
+            "Pruned {} of {} tools in {} to stay within --max-tools {}:",
+            pruned.len(),
+            pruned.len() + max_tools,
+            output_dir.display(),
+            max_tools
+        );
+        for tool in &pruned {
+            report.push_str(&format!(
+                "\n  - {} at {} (score {})",
+                tool.operation, tool.pointer, tool.score
+            ));
+        }
+        println!("{}", report);
+    }
+
+    // Report operations generated without their request body, in a single write as well.
+    if !unsupported.is_empty() {
+        let mut report = format!(
+            "Warning: generated {} tools in {} without a request body:",
+            unsupported.len(),
+            output_dir.display()
+        );
+        for warning in &unsupported {
+            report.push_str(&format!(
+                "\n  - {} at {}: {}",
+                warning.operation, warning.pointer, warning.message
+            ));
+        }
+        eprintln!("{}", report);
+    }
+
     Ok(())
 }
 
-fn main() -> Result<()> {
+/// Generates MCP server code for every server listed in a workspace config.
+///
+/// Servers are generated in parallel, sharing the options from the workspace config. A failing
+/// server does not stop the others; all failures are reported once every server has finished.
+///
+/// # Arguments
+///
+/// * `config` - Path to the workspace config file.
+/// * `force` - Whether to overwrite generated files in existing output directories.
+///
+/// # Returns
+///
+/// * `Result<()>` - Returns `Ok(())` if every server is generated, or an error if any of them fails.
+fn generate_workspace(config: &Path, force: bool) -> Result<()> {
+    let workspace = Workspace::load(config)?;
+
+    let results = thread::scope(|scope| {
+        let handles = workspace
+            .servers
+            .iter()
+            .map(|server| {
+                scope.spawn(|| {
+                    generate_mcp_server(&server.spec, &server.output, &workspace.options, force)
+                })
+            })
+            .collect::<Vec<_>>();
+
+        handles
+            .into_iter()
+            .map(|handle| handle.join().expect("generation thread panicked"))
+            .collect::<Vec<_>>()
+    });
+
+    let mut failed = 0;
+    for (server, result) in workspace.servers.iter().zip(results) {
+        if let Err(error) = result {
+            eprintln!("Failed to generate {}: {}", server.output.display(), error);
+            failed += 1;
+        }
+    }
+
+    if failed > 0 {
+        return Err(OpenApiToMcpError::WorkspaceGeneration {
+            failed,
+            total: workspace.servers.len(),
+        });
+    }
+
+    Ok(())
+}
+
+/// Runs a generate command, either for a single OpenAPI file or for a whole workspace.
+///
+/// # Arguments
+///
+/// * `args` - The parsed generate arguments.
+///
+/// # Returns
+///
+/// * `Result<()>` - Returns `Ok(())` if the generation succeeds, or an error if any step fails.
+fn run_generate(args: GenerateArgs) -> Result<()> {
+    if args.all {
+        return generate_workspace(&args.config, args.force);
+    }
+
+    // Clap guarantees both paths are present unless `--all` is set.
+    match (args.file, args.output) {
+        (Some(file), Some(output)) => {
+            generate_mcp_server(&file, &output, &args.options, args.force)
+        }
+        _ => unreachable!("--file and --output are required without --all"),
+    }
+}
+
+fn main() -> ExitCode {
     let args = Args::parse();
-    generate_mcp_server(&args.file, &args.output)
+    let result = match args.command {
+        Some(Command::Generate(generate)) => run_generate(generate),
+        None => run_generate(args.generate),
+    };
+
+    // Print errors with `Display`, so multi-line reports stay readable.
+    if let Err(error) = result {
+        eprintln!("Error: {}", error);
+        return ExitCode::FAILURE;
+    }
+
+    ExitCode::SUCCESS
 }
@@ -0,0 +1,264 @@
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+
+use crate::error::{OpenApiToMcpError, Result};
+
+/// Default location of the templates copied into every generated server.
+const DEFAULT_TEMPLATES_DIRECTORY: &str = "templates";
+
+/// Default interval, in seconds, at which subscribed resources poll the backend.
+const DEFAULT_POLL_INTERVAL_SECONDS: u64 = 30;
+
+/// Longest resource polling interval, in seconds. Timers wait at most 2^31-1 milliseconds; longer
+/// delays fire immediately.
+pub const MAX_POLL_INTERVAL_SECONDS: u64 = 2_147_483;
+
+/// The transport the generated server uses to talk to MCP clients.
+#[derive(clap::ValueEnum, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum Transport {
+    /// Standard input and output, for servers launched by the client.
+    #[default]
+    Stdio,
+    /// Streamable HTTP, for servers deployed as a service.
+    Http,
+}
+
+/// The runtime the generated server is deployed to.
+#[derive(clap::ValueEnum, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum Runtime {
+    /// A Node.js process, using the configured transport.
+    #[default]
+    Node,
+    /// A Cloudflare Worker serving Streamable HTTP, without Node-only APIs.
+    CloudflareWorkers,
+    /// An AWS Lambda function serving Streamable HTTP through API Gateway or a Function URL.
+    AwsLambda,
+}
+
+impl Runtime {
+    /// Whether the runtime handles every HTTP request in isolation, rather than running a
+    /// long-lived server process.
+    pub fn serves_per_request(self) -> bool {
+        self != Self::Node
+    }
+}
+
+/// Options that control how an MCP server is generated.
+///
+/// The same options are accepted as command-line flags and as the shared `[options]` table of a
+/// workspace config, so every server in a workspace is generated consistently.
+#[derive(clap::Args, Deserialize, Debug, Clone, Default)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+pub struct GeneratorOptions {
+    #[arg(
+        long,
+        help = "Directory containing the templates to copy [default: templates]"
+    )]
+    pub templates: Option<PathBuf>,
+
+    #[arg(
+        long,
+        help = "Expose event and notification endpoints as subscribable resources"
+    )]
+    pub watch_resources: bool,
+
+    #[arg(
+        long,
+        help = "Seconds between backend polls for subscribed resources [default: 30]"
+    )]
+    pub poll_interval: Option<u64>,
+
+    #[arg(
+        long,
+        help = "Expose request body properties as camelCase tool parameters"
+    )]
+    pub camel_case: bool,
+
+    #[arg(
+        long,
+        help = "Truncate tool descriptions longer than this many characters, serving the full text as a resource"
+    )]
+    pub max_description_length: Option<usize>,
+
+    #[arg(
+        long,
+        help = "Register at most this many tools, pruning the least useful operations and reporting them"
+    )]
+    pub max_tools: Option<usize>,
+
+    #[arg(
+        long,
+        help = "Include the curl command of every backend call in tool results, with secrets redacted"
+    )]
+    pub debug_curl: bool,
+
+    #[arg(
+        long,
+        value_enum,
+        help = "Transport the generated server uses to talk to MCP clients [default: stdio]"
+    )]
+    pub transport: Option<Transport>,
+
+    #[arg(
+        long,
+        value_enum,
+        default_value = "node",
+        help = "Runtime the generated server is deployed to; serverless runtimes always serve HTTP"
+    )]
+    pub runtime: Runtime,
+
+    #[arg(
+        long,
+        help = "Emit Kubernetes manifests and a Dockerfile (requires --transport http)"
+    )]
+    pub k8s: bool,
+
+    #[arg(
+        long,
+        requires = "k8s",
+        help = "Also emit a Kubernetes Ingress routing this host to the server"
+    )]
+    pub k8s_ingress_host: Option<String>,
+}
+
+impl GeneratorOptions {
+    /// Returns the templates directory, falling back to the default location.
+    pub fn templates_directory(&self) -> &Path {
+        self.templates
+            .as_deref()
+            .unwrap_or(Path::new(DEFAULT_TEMPLATES_DIRECTORY))
+    }
+
+    /// Returns the transport, falling back to the default.
+    pub fn transport(&self) -> Transport {
+        self.transport.unwrap_or_default()
+    }
+
+    /// Whether the generated server handles every MCP request with a server of its own, as
+    /// serverless runtimes and the stateless HTTP transport require.
+    pub fn server_per_request(&self) -> bool {
+        self.runtime.serves_per_request() || self.transport() == Transport::Http
+    }
+
+    /// Returns the resource polling interval in seconds, falling back to the default.
+    pub fn poll_interval_seconds(&self) -> u64 {
+        self.poll_interval.unwrap_or(DEFAULT_POLL_INTERVAL_SECONDS)
+    }
+
+    /// Checks that the options are consistent with each other.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns `Ok(())` if the options are valid, or an error describing the conflict.
+    pub fn validate(&self) -> Result<()> {
+        if let Some(poll_interval) = self.poll_interval {
+            if !(1..=MAX_POLL_INTERVAL_SECONDS).contains(&poll_interval) {
+                return Err(OpenApiToMcpError::PollIntervalOutOfRange(poll_interval));
+            }
+            if !self.watch_resources {
+                return Err(OpenApiToMcpError::PollIntervalRequiresWatchResources);
+            }
+        }
+        if self.runtime.serves_per_request() {
+            if self.k8s {
+                return Err(OpenApiToMcpError::UnsupportedRuntimeOption("--k8s"));
+            }
+            if self.watch_resources {
+                return Err(OpenApiToMcpError::UnsupportedRuntimeOption(
+                    "--watch-resources",
+                ));
+            }
+            if self.transport.is_some() {
+                return Err(OpenApiToMcpError::UnsupportedRuntimeOption("--transport"));
+            }
+        }
+        if self.watch_resources && self.transport() == Transport::Http {
+            return Err(OpenApiToMcpError::WatchResourcesRequiresStdio);
+        }
+        if self.k8s && self.transport() != Transport::Http {
+            return Err(OpenApiToMcpError::K8sRequiresHttpTransport);
+        }
+        if self.k8s_ingress_host.is_some() && !self.k8s {
+            return Err(OpenApiToMcpError::K8sIngressRequiresK8s);
+        }
+
+        Ok(())
+    }
+
+    /// Resolves relative paths in the options against the given base directory, including the
+    /// default paths of options that are not set.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The directory relative paths are resolved against.
+    pub fn resolve_paths(&mut self, base: &Path) {
+        self.templates = Some(base.join(self.templates_directory()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_rejects_zero_poll_interval() {
+        let options = GeneratorOptions {
+            watch_resources: true,
+            poll_interval: Some(0),
+            ..GeneratorOptions::default()
+        };
+
+        assert!(matches!(
+            options.validate(),
+            Err(OpenApiToMcpError::PollIntervalOutOfRange(0))
+        ));
+    }
+
+    #[test]
+    fn test_validate_rejects_poll_interval_beyond_timer_limit() {
+        let longest = GeneratorOptions {
+            watch_resources: true,
+            poll_interval: Some(MAX_POLL_INTERVAL_SECONDS),
+            ..GeneratorOptions::default()
+        };
+        let too_long = GeneratorOptions {
+            poll_interval: Some(MAX_POLL_INTERVAL_SECONDS + 1),
+            ..longest.clone()
+        };
+
+        assert!(longest.validate().is_ok());
+        assert!(matches!(
+            too_long.validate(),
+            Err(OpenApiToMcpError::PollIntervalOutOfRange(_))
+        ));
+    }
+
+    #[test]
+    fn test_validate_rejects_poll_interval_without_watch_resources() {
+        let options = GeneratorOptions {
+            poll_interval: Some(5),
+            ..GeneratorOptions::default()
+        };
+
+        assert!(matches!(
+            options.validate(),
+            Err(OpenApiToMcpError::PollIntervalRequiresWatchResources)
+        ));
+    }
+
+    #[test]
+    fn test_validate_rejects_transport_with_serverless_runtime() {
+        let options = GeneratorOptions {
+            runtime: Runtime::AwsLambda,
+            transport: Some(Transport::Stdio),
+            ..GeneratorOptions::default()
+        };
+
+        assert!(matches!(
+            options.validate(),
+            Err(OpenApiToMcpError::UnsupportedRuntimeOption("--transport"))
+        ));
+    }
+}
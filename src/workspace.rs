@@ -0,0 +1,182 @@
+use serde::Deserialize;
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::error::{OpenApiToMcpError, Result};
+use crate::options::GeneratorOptions;
+
+/// A workspace config listing several OpenAPI specs to generate MCP servers from.
+///
+/// ```toml
+/// [options]
+/// templates = "templates"
+///
+/// [[servers]]
+/// spec = "specs/users.json"
+/// output = "servers/users"
+/// ```
+#[derive(Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct Workspace {
+    /// Options shared by every server in the workspace.
+    #[serde(default)]
+    pub options: GeneratorOptions,
+
+    /// The spec to output mappings to generate.
+    #[serde(default)]
+    pub servers: Vec<ServerEntry>,
+}
+
+/// A single spec to output mapping in a workspace config.
+#[derive(Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct ServerEntry {
+    /// Path to the OpenAPI JSON file.
+    pub spec: PathBuf,
+
+    /// Path to write the output directory.
+    pub output: PathBuf,
+}
+
+impl Workspace {
+    /// Loads a workspace config from disk.
+    ///
+    /// Relative paths in the config are resolved against the directory containing the config file,
+    /// so the workspace can be generated from any working directory.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to the workspace config file.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Workspace>` - The parsed workspace, or an error if it cannot be read, parsed, is empty,
+    ///   or lists an output directory more than once.
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path)
+            .map_err(|_| OpenApiToMcpError::WorkspaceConfigRead(path.to_path_buf()))?;
+        let mut workspace: Workspace = toml::from_str(&content).map_err(|error| {
+            OpenApiToMcpError::WorkspaceConfigParse(path.to_path_buf(), error.to_string())
+        })?;
+
+        if workspace.servers.is_empty() {
+            return Err(OpenApiToMcpError::WorkspaceEmpty(path.to_path_buf()));
+        }
+
+        let base = path.parent().unwrap_or(Path::new(""));
+        workspace.options.resolve_paths(base);
+        for server in &mut workspace.servers {
+            server.spec = base.join(&server.spec);
+            server.output = base.join(&server.output);
+        }
+
+        // Servers are generated in parallel, so two of them must never write the same files.
+        let mut outputs = HashSet::new();
+        for server in &workspace.servers {
+            if !outputs.insert(&server.output) {
+                return Err(OpenApiToMcpError::WorkspaceDuplicateOutput(
+                    server.output.clone(),
+                ));
+            }
+        }
+
+        Ok(workspace)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Creates an empty directory unique to the test and process, so parallel runs never share it.
+    fn test_directory(name: &str) -> PathBuf {
+        let directory =
+            std::env::temp_dir().join(format!("mcpify-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&directory);
+        fs::create_dir_all(&directory).unwrap();
+        directory
+    }
+
+    #[test]
+    fn test_load_resolves_paths_against_config_directory() {
+        let directory = test_directory("workspace-paths");
+        let config = directory.join("mcpify.toml");
+        fs::write(
+            &config,
+            r#"
+[options]
+templates = "shared/templates"
+
+[[servers]]
+spec = "specs/users.json"
+output = "servers/users"
+"#,
+        )
+        .unwrap();
+
+        let workspace = Workspace::load(&config).unwrap();
+
+        assert_eq!(workspace.servers.len(), 1);
+        assert_eq!(
+            workspace.servers[0].spec,
+            directory.join("specs/users.json")
+        );
+        assert_eq!(workspace.servers[0].output, directory.join("servers/users"));
+        assert_eq!(
+            workspace.options.templates_directory(),
+            directory.join("shared/templates")
+        );
+        fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn test_load_resolves_default_templates_against_config_directory() {
+        let directory = test_directory("workspace-default-templates");
+        let config = directory.join("mcpify.toml");
+        fs::write(
+            &config,
+            r#"
+[[servers]]
+spec = "specs/users.json"
+output = "servers/users"
+"#,
+        )
+        .unwrap();
+
+        let workspace = Workspace::load(&config).unwrap();
+
+        fs::remove_dir_all(&directory).unwrap();
+        assert_eq!(
+            workspace.options.templates_directory(),
+            directory.join("templates")
+        );
+    }
+
+    #[test]
+    fn test_load_rejects_duplicate_outputs() {
+        let directory = test_directory("workspace-duplicates");
+        let config = directory.join("mcpify.toml");
+        fs::write(
+            &config,
+            r#"
+[[servers]]
+spec = "specs/users.json"
+output = "servers/api"
+
+[[servers]]
+spec = "specs/orders.json"
+output = "servers/./api"
+"#,
+        )
+        .unwrap();
+
+        let result = Workspace::load(&config);
+
+        fs::remove_dir_all(&directory).unwrap();
+        assert!(matches!(
+            result,
+            Err(OpenApiToMcpError::WorkspaceDuplicateOutput(_))
+        ));
+    }
+}